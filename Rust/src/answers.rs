@@ -0,0 +1,173 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{input, AocError, Result};
+
+/// Known-good answers for the real puzzle inputs, stored as `answers.toml`:
+///
+/// ```toml
+/// [day05]
+/// part1 = "382895070"
+/// part2 = "17729182"
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AnswerBook(BTreeMap<String, DayAnswers>);
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+}
+
+/// Outcome of checking one part's answer against the recorded one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    /// Nothing recorded for this part yet
+    Missing {
+        actual: String,
+    },
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail { expected, actual } => {
+                write!(f, "FAIL")?;
+                for line in expected.lines() {
+                    write!(f, "\n  - {line}")?;
+                }
+                for line in actual.lines() {
+                    write!(f, "\n  + {line}")?;
+                }
+                Ok(())
+            }
+            Verdict::Missing { actual } => write!(f, "MISSING (got {actual})"),
+        }
+    }
+}
+
+fn day_key(day: u32) -> String {
+    format!("day{:02}", day)
+}
+
+/// Default location, next to the puzzle inputs the answers belong to
+pub fn default_path() -> Result<PathBuf> {
+    Ok(input::puzzle_input_dir()?.join("answers.toml"))
+}
+
+impl AnswerBook {
+    /// Load from `path`. A missing file is an empty book.
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(AnswerBook::default()),
+            Err(e) => return Err(e.into()),
+        };
+        toml::from_str(&text).map_err(|e| AocError::AnswerFile {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let text = toml::to_string(self).map_err(|e| AocError::AnswerFile {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })?;
+        fs::write(path, text)?;
+        Ok(())
+    }
+
+    /// Recorded answer for `part` (1 or 2) of `day`
+    pub fn get(&self, day: u32, part: u32) -> Option<&str> {
+        let answers = self.0.get(&day_key(day))?;
+        match part {
+            1 => answers.part1.as_deref(),
+            2 => answers.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, day: u32, part: u32, answer: String) {
+        let answers = self.0.entry(day_key(day)).or_default();
+        match part {
+            1 => answers.part1 = Some(answer),
+            2 => answers.part2 = Some(answer),
+            _ => panic!("there are only two parts, got part {part}"),
+        }
+    }
+
+    /// Compare a part's result with the recorded answer. A part that errored
+    /// fails if there's an answer on record, and is missing otherwise.
+    pub fn check(&self, day: u32, part: u32, actual: &Result<String>) -> Verdict {
+        let actual = match actual {
+            Ok(answer) => answer.clone(),
+            Err(e) => format!("error: {e}"),
+        };
+        match self.get(day, part) {
+            Some(expected) if expected == actual => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+                actual,
+            },
+            None => Verdict::Missing { actual },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const ANSWERS: &str = r#"[day02]
+part1 = "2416"
+part2 = "63307"
+
+[day13]
+part1 = "33735"
+"#;
+
+    #[test]
+    fn test_round_trip() {
+        let book: AnswerBook = toml::from_str(ANSWERS).unwrap();
+        assert_eq!(book.get(2, 1), Some("2416"));
+        assert_eq!(book.get(13, 2), None);
+        assert_eq!(toml::to_string(&book).unwrap(), ANSWERS);
+    }
+
+    #[test]
+    fn test_check() {
+        let mut book = AnswerBook::default();
+        book.set(5, 1, "35".to_string());
+        assert_eq!(book.check(5, 1, &Ok("35".to_string())), Verdict::Pass);
+        assert_eq!(
+            book.check(5, 1, &Ok("36".to_string())),
+            Verdict::Fail {
+                expected: "35".to_string(),
+                actual: "36".to_string()
+            }
+        );
+        assert_eq!(
+            book.check(5, 2, &Err(AocError::Unsolved { day: 5, part: 2 })),
+            Verdict::Missing {
+                actual: "error: day 05 part 2 is not solved yet".to_string()
+            }
+        );
+    }
+}
@@ -0,0 +1,229 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    collections::BTreeMap,
+    fmt::Display,
+    fs,
+    path::Path,
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{AocError, Result};
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+
+/// Global allocator that counts allocations. Binaries opt in with
+/// `#[global_allocator]`; without it every measurement reports 0 allocations.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// Wall time and number of allocations for one run of a phase
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Measurement {
+    pub wall: Duration,
+    pub allocations: u64,
+}
+
+/// Run `f` and measure it
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Measurement) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    let result = f();
+    let wall = start.elapsed();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - allocations;
+    (result, Measurement { wall, allocations })
+}
+
+/// Phases of a solution that are timed separately
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Phase {
+    Parse,
+    PartOne,
+    PartTwo,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 3] = [Phase::Parse, Phase::PartOne, Phase::PartTwo];
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Phase::Parse => "parse",
+            Phase::PartOne => "part1",
+            Phase::PartTwo => "part2",
+        };
+        f.pad(name)
+    }
+}
+
+/// Measurements of every phase of a single solve
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Measurement,
+    pub part_one: Measurement,
+    pub part_two: Measurement,
+}
+
+impl Timings {
+    pub fn get(&self, phase: Phase) -> Measurement {
+        match phase {
+            Phase::Parse => self.parse,
+            Phase::PartOne => self.part_one,
+            Phase::PartTwo => self.part_two,
+        }
+    }
+}
+
+/// Summary of repeated wall time measurements
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    /// `None` if there are no samples
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let mid = sorted.len() / 2;
+        let median = match sorted.len() % 2 {
+            0 => (sorted[mid - 1] + sorted[mid]) / 2,
+            _ => sorted[mid],
+        };
+        let mean = sorted.iter().sum::<Duration>() / sorted.len() as u32;
+        Some(Stats {
+            min: sorted[0],
+            median,
+            mean,
+        })
+    }
+}
+
+/// Change from `baseline` to `current` in percent (positive is slower)
+pub fn percent_delta(baseline: Duration, current: Duration) -> f64 {
+    let baseline = baseline.as_nanos() as f64;
+    if baseline == 0.0 {
+        return 0.0;
+    }
+    (current.as_nanos() as f64 - baseline) * 100.0 / baseline
+}
+
+/// Median wall time per day and phase, saved as JSON so a later run can be
+/// compared against it: `{ "day05": { "parse": 12345, ... } }` (nanoseconds)
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Baseline(BTreeMap<String, BTreeMap<String, u64>>);
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path)?;
+        serde_json::from_str(&text).map_err(|e| AocError::Baseline {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let text = serde_json::to_string_pretty(self).map_err(|e| AocError::Baseline {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })?;
+        fs::write(path, text + "\n")?;
+        Ok(())
+    }
+
+    pub fn get(&self, day: u32, phase: Phase) -> Option<Duration> {
+        self.0
+            .get(&format!("day{:02}", day))?
+            .get(&phase.to_string())
+            .map(|nanos| Duration::from_nanos(*nanos))
+    }
+
+    pub fn set(&mut self, day: u32, phase: Phase, median: Duration) {
+        self.0
+            .entry(format!("day{:02}", day))
+            .or_default()
+            .insert(phase.to_string(), median.as_nanos() as u64);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn ms(ms: u64) -> Duration {
+        Duration::from_millis(ms)
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&[ms(5), ms(1), ms(3)]).unwrap();
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.median, ms(3));
+        assert_eq!(stats.mean, ms(3));
+
+        let stats = Stats::from_samples(&[ms(4), ms(1), ms(2), ms(9)]).unwrap();
+        assert_eq!(stats.median, ms(3));
+        assert_eq!(stats.mean, ms(4));
+
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_percent_delta() {
+        assert_eq!(percent_delta(ms(100), ms(125)), 25.0);
+        assert_eq!(percent_delta(ms(100), ms(50)), -50.0);
+        assert_eq!(percent_delta(ms(0), ms(50)), 0.0);
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let mut baseline = Baseline::default();
+        baseline.set(16, Phase::PartTwo, Duration::from_nanos(1_500));
+        let json = serde_json::to_string(&baseline).unwrap();
+        assert_eq!(json, r#"{"day16":{"part2":1500}}"#);
+        let loaded: Baseline = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            loaded.get(16, Phase::PartTwo),
+            Some(Duration::from_nanos(1_500))
+        );
+        assert_eq!(loaded.get(16, Phase::Parse), None);
+    }
+
+    #[test]
+    fn test_measure_without_counting_allocator() {
+        // The test harness doesn't install `CountingAllocator`, so only the
+        // wall time is meaningful here
+        let (v, measurement) = measure(|| vec![1u8; 16]);
+        assert_eq!(v.len(), 16);
+        assert_eq!(measurement.allocations, 0);
+    }
+}
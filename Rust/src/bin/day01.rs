@@ -1,9 +1,7 @@
-use rust_aoc2023::{get_puzzle_input_lines, Result};
+use std::process::ExitCode;
 
-fn main() -> Result<()> {
-    println!("Day 01");
-    for line in get_puzzle_input_lines(1)? {
-        println!("{:?}", line?);
-    }
-    Ok(())
+use rust_aoc2023::{days::day01::Day01, solution::run};
+
+fn main() -> ExitCode {
+    run::<Day01>()
 }
@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use rust_aoc2023::{days::day12::Day12, solution::run};
+
+fn main() -> ExitCode {
+    run::<Day12>()
+}
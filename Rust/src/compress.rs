@@ -0,0 +1,220 @@
+use crate::{grid::Grid2D, point::Point};
+
+/// One axis of a grid where each index `i` spans `weight(i)` real units
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WeightedAxis {
+    /// `prefix[i]` is the total weight of the indices before `i`
+    prefix: Vec<i64>,
+}
+
+impl WeightedAxis {
+    /// Axis with the given weight for each index
+    pub fn new(weights: impl IntoIterator<Item = i64>) -> Self {
+        let mut prefix = vec![0];
+        for weight in weights {
+            prefix.push(prefix.last().unwrap() + weight);
+        }
+        WeightedAxis { prefix }
+    }
+
+    /// Axis of `len` indices that weigh 1, except the `expanded` ones which
+    /// weigh `factor`. None if an expanded index isn't in `0..len`.
+    pub fn expanded(
+        len: usize,
+        expanded: impl IntoIterator<Item = i64>,
+        factor: i64,
+    ) -> Option<Self> {
+        let mut weights = vec![1; len];
+        for i in expanded {
+            *weights.get_mut(usize::try_from(i).ok()?)? = factor;
+        }
+        Some(WeightedAxis::new(weights))
+    }
+
+    /// Number of indices
+    pub fn len(&self) -> usize {
+        self.prefix.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Total weight of the axis
+    pub fn total(&self) -> i64 {
+        *self.prefix.last().unwrap()
+    }
+
+    /// Weight of index `i`, or None if it's not in `0..len()`
+    pub fn weight(&self, i: i64) -> Option<i64> {
+        let i = usize::try_from(i).ok()?;
+        Some(self.prefix.get(i + 1)? - self.prefix[i])
+    }
+
+    /// Real coordinate where index `i` starts; `len()` gives the total. None
+    /// if `i` is not in `0..=len()`.
+    pub fn to_real(&self, i: i64) -> Option<i64> {
+        self.prefix.get(usize::try_from(i).ok()?).copied()
+    }
+
+    /// Index whose span contains the real coordinate, if any
+    pub fn to_index(&self, real: i64) -> Option<i64> {
+        if !(0..self.total()).contains(&real) {
+            return None;
+        }
+        // Last index starting at or before `real`; skips zero-weight indices
+        Some(self.prefix.partition_point(|&start| start <= real) as i64 - 1)
+    }
+}
+
+/// Sorted distinct coordinates, numbered densely from 0
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Compressed {
+    values: Vec<i64>,
+}
+
+impl Compressed {
+    pub fn new(values: impl IntoIterator<Item = i64>) -> Self {
+        let mut values: Vec<i64> = values.into_iter().collect();
+        values.sort_unstable();
+        values.dedup();
+        Compressed { values }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Dense index of a coordinate, if it's one of the compressed ones
+    pub fn index(&self, value: i64) -> Option<i64> {
+        self.values.binary_search(&value).ok().map(|i| i as i64)
+    }
+
+    /// Coordinate with the given dense index, if there is one
+    pub fn value(&self, index: i64) -> Option<i64> {
+        self.values.get(usize::try_from(index).ok()?).copied()
+    }
+
+    /// Axis where each index weighs the distance to the next coordinate (and
+    /// the last one weighs 1), so areas can be measured on the dense grid
+    pub fn gaps(&self) -> WeightedAxis {
+        WeightedAxis::new(
+            self.values
+                .windows(2)
+                .map(|w| w[1] - w[0])
+                .chain((!self.values.is_empty()).then_some(1)),
+        )
+    }
+}
+
+/// Sparse points squeezed into a dense grid with one row and column per
+/// distinct coordinate
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompressedGrid<T> {
+    pub xs: Compressed,
+    pub ys: Compressed,
+    pub grid: Grid2D<T>,
+}
+
+impl<T> CompressedGrid<T>
+where
+    T: PartialEq + Clone + Copy,
+{
+    /// Place each `(point, value)` on the dense grid; every other cell is `fill`
+    pub fn new(points: &[(Point, T)], fill: T) -> Self {
+        let xs = Compressed::new(points.iter().map(|(p, _)| p.x));
+        let ys = Compressed::new(points.iter().map(|(p, _)| p.y));
+        let mut grid = Grid2D {
+            width: xs.len() as i64,
+            height: ys.len() as i64,
+            cells: vec![fill; xs.len() * ys.len()],
+        };
+        for (p, value) in points {
+            let dense = Point::new(xs.index(p.x).unwrap(), ys.index(p.y).unwrap());
+            grid.set(dense, *value);
+        }
+        CompressedGrid { xs, ys, grid }
+    }
+
+    /// Dense grid coordinate of a real point, if both its coordinates are known
+    pub fn grid_point(&self, real: Point) -> Option<Point> {
+        Some(Point::new(self.xs.index(real.x)?, self.ys.index(real.y)?))
+    }
+
+    /// Real point for a dense grid coordinate, if it's on the grid
+    pub fn real_point(&self, p: Point) -> Option<Point> {
+        Some(Point::new(self.xs.value(p.x)?, self.ys.value(p.y)?))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_weighted_axis() {
+        let axis = WeightedAxis::expanded(6, [1, 4], 10).unwrap();
+        assert_eq!(axis.len(), 6);
+        assert_eq!(axis.total(), 24);
+        let starts: Vec<i64> = (0..=6).filter_map(|i| axis.to_real(i)).collect();
+        assert_eq!(starts, vec![0, 1, 11, 12, 13, 23, 24]);
+        assert_eq!(axis.weight(4), Some(10));
+        assert_eq!(axis.weight(5), Some(1));
+        assert_eq!(axis.weight(6), None);
+        assert_eq!(axis.weight(-1), None);
+        assert_eq!(axis.to_real(7), None);
+        assert_eq!(axis.to_real(-1), None);
+        assert_eq!(axis.to_index(0), Some(0));
+        assert_eq!(axis.to_index(1), Some(1));
+        assert_eq!(axis.to_index(10), Some(1));
+        assert_eq!(axis.to_index(11), Some(2));
+        assert_eq!(axis.to_index(23), Some(5));
+        assert_eq!(axis.to_index(24), None);
+        assert_eq!(axis.to_index(-1), None);
+        // Zero-weight indices take up no real coordinates
+        let axis = WeightedAxis::new([2, 0, 3]);
+        assert_eq!(axis.to_index(2), Some(2));
+        assert_eq!(WeightedAxis::expanded(6, [6], 10), None);
+        assert_eq!(WeightedAxis::expanded(6, [-1], 10), None);
+    }
+
+    #[test]
+    fn test_compressed() {
+        let xs = Compressed::new([1_000_000, -5, 42, 42]);
+        assert_eq!(xs.len(), 3);
+        assert_eq!(xs.index(42), Some(1));
+        assert_eq!(xs.index(43), None);
+        assert_eq!(xs.value(2), Some(1_000_000));
+        assert_eq!(xs.value(3), None);
+        assert_eq!(xs.value(-1), None);
+        let gaps = xs.gaps();
+        assert_eq!(gaps.total(), 1_000_006);
+        assert_eq!(gaps.to_index(100), Some(1));
+    }
+
+    #[test]
+    fn test_compressed_grid() {
+        let points = [
+            (Point::new(-1_000, 5), 'a'),
+            (Point::new(7, 5), 'b'),
+            (Point::new(7, 9_000_000), 'c'),
+        ];
+        let compressed = CompressedGrid::new(&points, '.');
+        assert_eq!(compressed.grid.to_string(), "ab\n.c\n");
+        assert_eq!(
+            compressed.grid_point(Point::new(7, 9_000_000)),
+            Some(Point::new(1, 1))
+        );
+        assert_eq!(compressed.grid_point(Point::new(8, 5)), None);
+        assert_eq!(
+            compressed.real_point(Point::new(0, 1)),
+            Some(Point::new(-1_000, 9_000_000))
+        );
+        assert_eq!(compressed.real_point(Point::new(2, 0)), None);
+        assert_eq!(compressed.real_point(Point::new(0, -1)), None);
+    }
+}
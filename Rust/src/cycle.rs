@@ -0,0 +1,123 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Shape of a sequence of states `x0, x1 = step(x0), ...` that eventually
+/// repeats: after `mu` steps it enters a loop of `lambda` states
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// Index of the first state that is part of the loop
+    pub mu: usize,
+    /// Length of the loop
+    pub lambda: usize,
+}
+
+impl Cycle {
+    /// Earliest step whose state is the same as the one at step `n`
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        match n < self.mu {
+            true => n,
+            false => self.mu + (n - self.mu) % self.lambda,
+        }
+    }
+
+    /// State at step `n`, only simulating up to the equivalent step
+    pub fn extrapolate<S: Clone>(&self, start: &S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+        (0..self.equivalent_step(n)).fold(start.clone(), |state, _| step(&state))
+    }
+}
+
+/// Brent's cycle detection, keeping only two states around at a time
+pub fn brent<S: Clone + PartialEq>(start: &S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // Find lambda by racing the hare ahead of a tortoise that teleports to
+    // the hare at every power of two
+    let mut power = 1;
+    let mut lambda = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(start);
+    while tortoise != hare {
+        if power == lambda {
+            tortoise = hare.clone();
+            power *= 2;
+            lambda = 0;
+        }
+        hare = step(&hare);
+        lambda += 1;
+    }
+
+    // With the hare lambda steps ahead, both meet at the start of the loop
+    let mut mu = 0;
+    let mut tortoise = start.clone();
+    let mut hare = (0..lambda).fold(start.clone(), |state, _| step(&state));
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        mu += 1;
+    }
+    Cycle { mu, lambda }
+}
+
+/// Cycle detection that remembers when each state was first seen. Costs
+/// memory for every state, but stops as soon as the first state repeats and
+/// returns the states seen so far (`states[i]` is the state at step `i`).
+pub fn find_cycle<S: Clone + Eq + Hash>(
+    start: S,
+    mut step: impl FnMut(&S) -> S,
+) -> (Cycle, Vec<S>) {
+    let mut first_seen = HashMap::new();
+    let mut states = vec![];
+    let mut state = start;
+    loop {
+        if let Some(&mu) = first_seen.get(&state) {
+            let lambda = states.len() - mu;
+            return (Cycle { mu, lambda }, states);
+        }
+        first_seen.insert(state.clone(), states.len());
+        let next = step(&state);
+        states.push(state);
+        state = next;
+    }
+}
+
+/// State at step `n` of the sequence starting at `start`, however large `n` is
+pub fn state_at<S: Clone + Eq + Hash>(start: S, step: impl FnMut(&S) -> S, n: usize) -> S {
+    let (cycle, mut states) = find_cycle(start, step);
+    states.swap_remove(cycle.equivalent_step(n))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 6 -> 3 -> ...
+    fn step(x: &u32) -> u32 {
+        match x {
+            6 => 3,
+            x => x + 1,
+        }
+    }
+
+    #[test]
+    fn test_brent() {
+        assert_eq!(brent(&0, step), Cycle { mu: 3, lambda: 4 });
+        assert_eq!(brent(&4, step), Cycle { mu: 0, lambda: 4 });
+        // A fixed point is a loop of one
+        assert_eq!(brent(&7, |_| 7), Cycle { mu: 0, lambda: 1 });
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let (cycle, states) = find_cycle(0, step);
+        assert_eq!(cycle, Cycle { mu: 3, lambda: 4 });
+        assert_eq!(states, vec![0, 1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        let cycle = brent(&0, step);
+        assert_eq!(cycle.equivalent_step(2), 2);
+        assert_eq!(cycle.equivalent_step(7), 3);
+        assert_eq!(cycle.equivalent_step(1_000_000_000), 4);
+        assert_eq!(cycle.extrapolate(&0, step, 1_000_000_000), 4);
+        assert_eq!(state_at(0, step, 1_000_000_000), 4);
+        assert_eq!(state_at(0, step, 1), 1);
+    }
+}
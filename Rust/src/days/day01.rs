@@ -0,0 +1,28 @@
+use crate::{solution::Solution, AocError, Result};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+    type Input<'a> = Vec<&'a str>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input.lines().collect())
+    }
+
+    fn part_one(_input: &Self::Input<'_>) -> Result<Self::PartOne> {
+        Err(AocError::Unsolved {
+            day: Self::DAY,
+            part: 1,
+        })
+    }
+
+    fn part_two(_input: &Self::Input<'_>) -> Result<Self::PartTwo> {
+        Err(AocError::Unsolved {
+            day: Self::DAY,
+            part: 2,
+        })
+    }
+}
@@ -0,0 +1,187 @@
+use std::cmp::Ordering;
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{space1, u32},
+    combinator::{all_consuming, cut},
+    multi::separated_list1,
+    sequence::{separated_pair, tuple},
+    IResult,
+};
+
+use crate::{solution::Solution, AocError, Result};
+
+pub struct Day02;
+
+/// Game id and the record of each handful of cubes revealed
+type Game = (u32, Vec<Record>);
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Record {
+    r: u32,
+    g: u32,
+    b: u32,
+}
+
+impl Default for Record {
+    fn default() -> Self {
+        Record { r: 1, g: 1, b: 1 }
+    }
+}
+
+#[allow(clippy::double_comparisons)]
+impl PartialOrd for Record {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        if self < other {
+            Some(Ordering::Less)
+        } else if self > other {
+            Some(Ordering::Greater)
+        } else if self == other {
+            Some(Ordering::Equal)
+        } else {
+            None
+        }
+    }
+    fn le(&self, other: &Self) -> bool {
+        (self.r < other.r || self.r == other.r)
+            && (self.g < other.g || self.g == other.g)
+            && (self.b < other.b || self.b == other.b)
+    }
+    fn ge(&self, other: &Self) -> bool {
+        (self.r > other.r || self.r == other.r)
+            && (self.g > other.g || self.g == other.g)
+            && (self.b > other.b || self.b == other.b)
+    }
+    fn lt(&self, other: &Self) -> bool {
+        (self.r < other.r) && (self.g < other.g) && (self.b < other.b)
+    }
+    fn gt(&self, other: &Self) -> bool {
+        (self.r > other.r) && (self.g > other.g) && (self.b > other.b)
+    }
+}
+
+impl Record {
+    fn max_vals(&mut self, other: &Record) {
+        self.r = std::cmp::max(self.r, other.r);
+        self.g = std::cmp::max(self.g, other.g);
+        self.b = std::cmp::max(self.b, other.b);
+    }
+
+    fn power(&self) -> u32 {
+        self.r * self.g * self.b
+    }
+}
+
+fn parse_num_and_color(input: &str) -> IResult<&str, (u32, &str)> {
+    separated_pair(u32, space1, alt((tag("red"), tag("green"), tag("blue"))))(input)
+}
+
+fn parse_record(input: &str) -> IResult<&str, Record> {
+    let (rest, cubes) = separated_list1(tag(", "), cut(parse_num_and_color))(input)?;
+    let mut record = Record { r: 0, g: 0, b: 0 };
+    for (num, color) in cubes {
+        match color {
+            "red" => record.r = num,
+            "green" => record.g = num,
+            "blue" => record.b = num,
+            _ => unreachable!(),
+        }
+    }
+    Ok((rest, record))
+}
+
+fn parse_line(input: &str) -> IResult<&str, Game> {
+    let (rest, (_, game_id, _)) = tuple((tag("Game "), u32, tag(": ")))(input)?;
+    let (rest, records) = separated_list1(tag("; "), parse_record)(rest)?;
+    Ok((rest, (game_id, records)))
+}
+
+fn part1(games: &[Game]) -> u32 {
+    const CMP_RECORD: Record = Record {
+        r: 12,
+        g: 13,
+        b: 14,
+    };
+    games
+        .iter()
+        .filter_map(
+            |(game_id, records)| match records.iter().all(|r| r <= &CMP_RECORD) {
+                true => Some(game_id),
+                false => None,
+            },
+        )
+        .sum::<u32>()
+}
+
+fn part2(games: &[Game]) -> u32 {
+    games
+        .iter()
+        .map(|(_, records)| {
+            records
+                .iter()
+                .fold(Record::default(), |mut orig, rec| {
+                    orig.max_vals(rec);
+                    orig
+                })
+                .power()
+        })
+        .sum::<u32>()
+}
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+    const EXAMPLES: &'static [&'static str] = &[TEST_INPUT];
+    type Input<'a> = Vec<Game>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                all_consuming(parse_line)(line)
+                    .map(|(_, game)| game)
+                    .map_err(|e| AocError::from_nom(Self::DAY, i + 1, line, e))
+            })
+            .collect()
+    }
+
+    fn part_one(games: &Self::Input<'_>) -> Result<Self::PartOne> {
+        Ok(part1(games))
+    }
+
+    fn part_two(games: &Self::Input<'_>) -> Result<Self::PartTwo> {
+        Ok(part2(games))
+    }
+}
+
+const TEST_INPUT: &str = r#"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+"#;
+
+crate::aoc_examples! {
+    solution: Day02,
+    input: TEST_INPUT,
+    part1: 8,
+    part2: 2286,
+}
+
+#[test]
+fn test_parse_error_position() {
+    let input = "Game 1: 3 blue, 4 red\nGame 2: 1 blue, 2 purple";
+    let err = Day02::parse(input).unwrap_err();
+    assert!(matches!(
+        err,
+        AocError::Parse {
+            day: 2,
+            line: 2,
+            column: 19,
+            ..
+        }
+    ));
+}
@@ -0,0 +1,151 @@
+use self::GridCell::*;
+use crate::{grid::Grid2D, point::Point, solution::Solution, Result};
+use std::collections::HashSet;
+
+pub struct Day03;
+type Grid = Grid2D<GridCell>;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum GridCell {
+    Number(u16),
+    Symbol(char),
+    Empty,
+}
+
+/// Parse puzzle input into a "2-D Grid" (flat array) of `GridCell` Enum values
+fn parse_input(input: &str) -> Result<Grid> {
+    Grid2D::parse_with(input, |_, val| {
+        Ok(match val {
+            '0'..='9' => GridCell::Number(val.to_digit(10).unwrap() as u16),
+            '.' => GridCell::Empty,
+            _ => GridCell::Symbol(val),
+        })
+    })
+}
+
+/// Return a number created by joining adjacent horizontal digits in a grid
+fn gen_number_from_pt(grid: &Grid, pt: Point) -> (usize, HashSet<Point>) {
+    let start_num = if let Some(Number(n)) = grid.get_ref(pt) {
+        n
+    } else {
+        panic!("Should be a number")
+    };
+    let mut digits: Vec<u16> = vec![*start_num];
+    let mut coord_set = HashSet::from_iter(vec![pt]);
+    // search left
+    for lx in (pt.x - 3..pt.x).rev() {
+        match grid.get_ref((lx, pt.y).into()) {
+            Some(GridCell::Number(n)) => {
+                digits.insert(0, *n);
+                coord_set.insert((lx, pt.y).into());
+            }
+            Some(_) | None => break,
+        }
+    }
+    // search right
+    for rx in pt.x + 1..pt.x + 3 {
+        match grid.get_ref((rx, pt.y).into()) {
+            Some(GridCell::Number(n)) => {
+                digits.push(*n);
+                coord_set.insert((rx, pt.y).into());
+            }
+            Some(_) | None => break,
+        }
+    }
+    (
+        digits.into_iter().fold(0, |acc, i| acc * 10 + i) as usize,
+        coord_set,
+    )
+}
+
+/// Find coordinates of numbers adjacent to passed-in point
+/// construct separate horizontal digits into whole numbers and return them
+fn get_adjacent_numbers(grid: &Grid, pt: Point, visited: &mut HashSet<Point>) -> Vec<usize> {
+    let mut nums: Vec<usize> = vec![];
+    let adjacent_num_coords: Vec<Point> = grid
+        .get_neighbor8_coords(pt)
+        .iter()
+        .filter_map(|new_pt| match grid.get_ref(*new_pt) {
+            Some(GridCell::Number(_)) => Some(*new_pt),
+            _ => None,
+        })
+        .collect();
+
+    for coord in adjacent_num_coords {
+        if visited.contains(&coord) {
+            continue;
+        }
+        let (val, visited_coords) = gen_number_from_pt(grid, coord);
+        visited.extend(visited_coords);
+        nums.push(val);
+    }
+    nums
+}
+
+/// Iterate through the grid cells, getting the coordinates of
+/// types `GridCell::Symbol`, find the numbers adjacent to them
+/// then sum all of those numbers
+fn solve_part_1(grid: &Grid) -> usize {
+    let mut visited = HashSet::new();
+    grid.iter_points()
+        .filter(|(_, c)| matches!(c, GridCell::Symbol(_)))
+        .flat_map(|(pt, _)| get_adjacent_numbers(grid, pt, &mut visited))
+        .sum::<usize>()
+}
+
+/// Find coordinates of all gears ('`*`' symbol)
+/// filter for just ones that are touching exactly two numbers
+/// multiply those two numbers
+/// sum all the multiples
+fn solve_part_2(grid: &Grid) -> usize {
+    let mut visited = HashSet::new();
+    grid.iter_points()
+        .filter(|(_, cell)| **cell == GridCell::Symbol('*'))
+        .filter_map(|(coord, _)| {
+            let nums = get_adjacent_numbers(grid, coord, &mut visited);
+            match nums.len() {
+                2 => Some(nums.iter().product::<usize>()),
+                _ => None,
+            }
+        })
+        .sum::<usize>()
+}
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+    const EXAMPLES: &'static [&'static str] = &[TEST_INPUT];
+    type Input<'a> = Grid;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input).map_err(|e| e.for_day(Self::DAY))
+    }
+
+    fn part_one(grid: &Self::Input<'_>) -> Result<Self::PartOne> {
+        Ok(solve_part_1(grid))
+    }
+
+    fn part_two(grid: &Self::Input<'_>) -> Result<Self::PartTwo> {
+        Ok(solve_part_2(grid))
+    }
+}
+
+const TEST_INPUT: &str = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+crate::aoc_examples! {
+    solution: Day03,
+    input: TEST_INPUT,
+    part1: 4361,
+    part2: 467835,
+}
@@ -0,0 +1,93 @@
+use std::collections::{HashMap, HashSet};
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{alpha1, multispace1, u32},
+    combinator::all_consuming,
+    multi::separated_list1,
+    sequence::tuple,
+    IResult,
+};
+
+use crate::{solution::Solution, AocError, Result};
+
+pub struct Day04;
+
+/// Card id and the number of winning numbers on that card
+type Card = (u32, u32);
+
+fn num_list_parser(s: &str) -> IResult<&str, Vec<u32>> {
+    separated_list1(multispace1, u32)(s)
+}
+
+fn card_id_parser(s: &str) -> IResult<&str, u32> {
+    let (rest, (_, _, card_id)) = tuple((alpha1, multispace1, u32))(s)?;
+    Ok((rest, card_id))
+}
+
+fn line_parser(s: &str) -> IResult<&str, Card> {
+    let (rest, (card_id, _, _, winning_nums, _, _, _, card_nums)) = tuple((
+        card_id_parser,
+        tag(":"),
+        multispace1,
+        num_list_parser,
+        multispace1,
+        tag("|"),
+        multispace1,
+        num_list_parser,
+    ))(s)?;
+    let winning_nums: HashSet<u32> = HashSet::from_iter(winning_nums);
+    let card_nums: HashSet<u32> = HashSet::from_iter(card_nums);
+    Ok((
+        rest,
+        (
+            card_id,
+            winning_nums.intersection(&card_nums).count() as u32,
+        ),
+    ))
+}
+
+fn part_1(cards: &[Card]) -> u32 {
+    cards
+        .iter()
+        .map(|(_, score)| 2f32.powi(*score as i32 - 1) as u32)
+        .sum()
+}
+
+fn part_2(cards: &[Card]) -> u32 {
+    let scores: HashMap<u32, u32> = HashMap::from_iter(cards.iter().copied());
+    let mut card_counts = vec![1; scores.len()];
+    for i in 0..scores.len() {
+        for j in i + 1..(i as u32 + scores[&(i as u32 + 1)] + 1) as usize {
+            card_counts[j] += card_counts[i]
+        }
+    }
+    card_counts.iter().sum()
+}
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+    type Input<'a> = Vec<Card>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                all_consuming(line_parser)(line)
+                    .map(|(_, card)| card)
+                    .map_err(|e| AocError::from_nom(Self::DAY, i + 1, line, e))
+            })
+            .collect()
+    }
+
+    fn part_one(cards: &Self::Input<'_>) -> Result<Self::PartOne> {
+        Ok(part_1(cards))
+    }
+
+    fn part_two(cards: &Self::Input<'_>) -> Result<Self::PartTwo> {
+        Ok(part_2(cards))
+    }
+}
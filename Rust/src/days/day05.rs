@@ -0,0 +1,278 @@
+use std::ops::Range;
+
+use nom::{
+    bytes::complete::{tag, take_while1},
+    character::complete::{space1, u64},
+    combinator::{all_consuming, cut},
+    multi::separated_list1,
+    sequence::{preceded, terminated, tuple},
+    IResult,
+};
+
+use crate::{
+    interval::{IntervalSet, RangeMap},
+    solution::Solution,
+    AocError, Result,
+};
+
+pub struct Day05;
+
+#[derive(Debug)]
+pub struct SeedMap {
+    seeds: Vec<u64>,
+    /// The seeds line read as pairs of range start and length
+    seed_ranges: IntervalSet<u64>,
+    /// One map per category, from seed-to-soil through humidity-to-location
+    maps: Vec<RangeMap<u64>>,
+}
+
+impl SeedMap {
+    /// Find the location corresponding to a seed
+    fn get_location_single_seed(&self, seed: u64) -> u64 {
+        self.maps.iter().fold(seed, |value, map| map.get(value))
+    }
+}
+
+/// `seeds: 79 14 55 13`
+fn parse_seeds(input: &str) -> IResult<&str, Vec<u64>> {
+    preceded(tag("seeds: "), cut(separated_list1(space1, u64)))(input)
+}
+
+/// `seed-to-soil map:`
+fn parse_header(input: &str) -> IResult<&str, &str> {
+    terminated(
+        take_while1(|c: char| c.is_ascii_alphabetic() || c == '-'),
+        tag(" map:"),
+    )(input)
+}
+
+/// Destination start, source start and length: `50 98 2`
+fn parse_entry(input: &str) -> IResult<&str, (u64, u64, u64)> {
+    tuple((u64, preceded(space1, u64), preceded(space1, u64)))(input)
+}
+
+fn parse_error(line: usize, message: &str) -> AocError {
+    AocError::Parse {
+        day: Day05::DAY,
+        line,
+        column: 1,
+        message: message.to_string(),
+    }
+}
+
+/// `start..start + len`, or None if the end doesn't fit in a `u64`
+fn span(start: u64, len: u64) -> Option<Range<u64>> {
+    Some(start..start.checked_add(len)?)
+}
+
+fn parse_input(input: &str) -> Result<SeedMap> {
+    let error = |line_no: usize, line: &str, e| AocError::from_nom(Day05::DAY, line_no, line, e);
+    let mut lines = input.lines();
+    let first = lines.next().unwrap_or_default();
+    let (_, seeds) = all_consuming(parse_seeds)(first).map_err(|e| error(1, first, e))?;
+    if seeds.len() % 2 != 0 {
+        return Err(parse_error(1, "seeds don't pair up into ranges"));
+    }
+    let seed_ranges = seeds
+        .chunks_exact(2)
+        .map(|pair| span(pair[0], pair[1]))
+        .collect::<Option<IntervalSet<u64>>>()
+        .ok_or_else(|| parse_error(1, "seed range overflows"))?;
+
+    let mut maps: Vec<RangeMap<u64>> = vec![];
+    for (i, line) in lines.enumerate() {
+        let line_no = i + 2;
+        if line.is_empty() {
+            continue;
+        }
+        if all_consuming(parse_header)(line).is_ok() {
+            maps.push(RangeMap::new());
+            continue;
+        }
+        let (_, (destination, source, len)) =
+            all_consuming(parse_entry)(line).map_err(|e| error(line_no, line, e))?;
+        let Some(map) = maps.last_mut() else {
+            return Err(parse_error(line_no, "map entry before any map header"));
+        };
+        // Both ends of the segment must fit, or mapping values would overflow
+        let source = match (span(source, len), span(destination, len)) {
+            (Some(source), Some(_)) => source,
+            _ => return Err(parse_error(line_no, "map entry overflows")),
+        };
+        if !map.insert(source, destination) {
+            return Err(parse_error(
+                line_no,
+                "source range overlaps an earlier entry",
+            ));
+        }
+    }
+    Ok(SeedMap {
+        seeds,
+        seed_ranges,
+        maps,
+    })
+}
+
+fn part1(seed_map: &SeedMap) -> Option<u64> {
+    seed_map
+        .seeds
+        .iter()
+        .map(|s| seed_map.get_location_single_seed(*s))
+        .min()
+}
+
+fn part2(seed_map: &SeedMap) -> Option<u64> {
+    seed_map
+        .maps
+        .iter()
+        .fold(seed_map.seed_ranges.clone(), |ranges, map| {
+            map.map_set(&ranges)
+        })
+        .min()
+}
+
+fn no_seeds(message: &str) -> AocError {
+    AocError::Unsolvable {
+        day: Day05::DAY,
+        message: message.to_string(),
+    }
+}
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+    const EXAMPLES: &'static [&'static str] = &[SAMPLE_INPUT];
+    type Input<'a> = SeedMap;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part_one(seed_map: &Self::Input<'_>) -> Result<Self::PartOne> {
+        part1(seed_map).ok_or_else(|| no_seeds("no seeds"))
+    }
+
+    fn part_two(seed_map: &Self::Input<'_>) -> Result<Self::PartTwo> {
+        part2(seed_map).ok_or_else(|| no_seeds("no seed ranges"))
+    }
+}
+
+const SAMPLE_INPUT: &str = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+crate::aoc_examples! {
+    solution: Day05,
+    input: SAMPLE_INPUT,
+    part1: 35,
+    part2: 46,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        let seed_map = parse_input(SAMPLE_INPUT).unwrap();
+        assert_eq!(seed_map.seeds.len(), 4);
+        assert_eq!(seed_map.maps.len(), 7);
+    }
+
+    #[test]
+    fn test_get_seed_value() {
+        let seed_map = parse_input(SAMPLE_INPUT).unwrap();
+        let test_map = &seed_map.maps[0];
+        assert_eq!(test_map.get(79), 81);
+        assert_eq!(test_map.get(10), 10);
+    }
+
+    #[test]
+    fn test_parse_error_position() {
+        let err = Day05::parse("seeds: 79 14\n\nseed-to-soil map:\n50 98\n").unwrap_err();
+        assert!(matches!(
+            err,
+            AocError::Parse {
+                day: 5,
+                line: 4,
+                column: 6,
+                ..
+            }
+        ));
+        let err = Day05::parse("seeds: 79 x4").unwrap_err();
+        assert!(matches!(
+            err,
+            AocError::Parse {
+                line: 1,
+                column: 10,
+                ..
+            }
+        ));
+        let err = Day05::parse("seeds: 79 14\n\n50 98 2").unwrap_err();
+        assert!(matches!(
+            err,
+            AocError::Parse {
+                line: 3,
+                column: 1,
+                ..
+            }
+        ));
+        let err = Day05::parse("seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n0 90 9").unwrap_err();
+        assert!(matches!(
+            err,
+            AocError::Parse {
+                line: 5,
+                column: 1,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_bad_ranges() {
+        let line_of = |input: &str| match Day05::parse(input) {
+            Err(AocError::Parse { day: 5, line, .. }) => Some(line),
+            _ => None,
+        };
+        // An odd number of seeds doesn't make ranges
+        assert_eq!(line_of("seeds: 79 14 55"), Some(1));
+        assert_eq!(line_of("seeds: 18446744073709551615 1"), Some(1));
+        let entry = |entry: &str| format!("seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n{entry}");
+        assert_eq!(line_of(&entry("0 18446744073709551615 1")), Some(5));
+        assert_eq!(line_of(&entry("18446744073709551615 0 1")), Some(5));
+        // Ends right at u64::MAX are fine
+        assert_eq!(line_of(&entry("0 18446744073709551614 1")), None);
+    }
+}
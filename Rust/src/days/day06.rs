@@ -0,0 +1,93 @@
+use itertools::join;
+
+use crate::{solution::Solution, Result};
+
+pub struct Day06;
+
+fn u64_vec_from_str(input: &str) -> Vec<u64> {
+    input
+        .split_ascii_whitespace()
+        .skip(1)
+        .filter_map(|d| d.parse::<u64>().ok())
+        .collect()
+}
+
+fn parse_input(input: String) -> Vec<(u64, u64)> {
+    let mut lines = input.lines();
+    let times = u64_vec_from_str(lines.next().unwrap());
+    let distances = u64_vec_from_str(lines.next().unwrap());
+    times
+        .iter()
+        .copied()
+        .zip(distances.iter().copied())
+        .collect()
+}
+
+fn join_u64(input: &str) -> u64 {
+    join(input.split_ascii_whitespace().skip(1), "")
+        .parse()
+        .unwrap()
+}
+
+fn calc_possible_wins(time: u64, distance: u64) -> Option<u64> {
+    // Second half is a mirror of the first half, only calculate first part
+    for hold_time in 1..=time / 2 {
+        // If the hold_time crosses the threshold, the following ones will too
+        if hold_time * (time - hold_time) > distance {
+            // There are time + 1 options (include 0)
+            // Subtract twice the minimum required hold time, which also removes the longest
+            // push times
+            return Some(time + 1 - 2 * hold_time);
+        }
+    }
+    None
+}
+
+fn part1(data: &[(u64, u64)]) -> u64 {
+    data.iter()
+        .filter_map(|(time, distance)| calc_possible_wins(*time, *distance))
+        .product()
+}
+
+fn part2(input: &str) -> u64 {
+    let time = join_u64(input.lines().next().unwrap());
+    let distance = join_u64(input.lines().nth(1).unwrap());
+    calc_possible_wins(time, distance).unwrap()
+}
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+    const EXAMPLES: &'static [&'static str] = &[TEST_INPUT];
+    type Input<'a> = &'a str;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne> {
+        Ok(part1(&parse_input(input.to_string())))
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo> {
+        Ok(part2(input))
+    }
+}
+
+const TEST_INPUT: &str = "\
+Time:      7  15   30
+Distance:  9  40  200";
+
+crate::aoc_examples! {
+    solution: Day06,
+    input: TEST_INPUT,
+    part1: 288,
+    part2: 71503,
+}
+
+#[test]
+fn test_join_u64() {
+    let num = join_u64(TEST_INPUT.lines().next().unwrap());
+    assert_eq!(num, 71530);
+}
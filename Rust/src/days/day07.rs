@@ -0,0 +1,220 @@
+use std::cmp::Ordering;
+
+use itertools::Itertools;
+
+use nom::{
+    character::complete::{one_of, space1, u32},
+    combinator::{all_consuming, cut, map, recognize},
+    multi::count,
+    sequence::separated_pair,
+    IResult,
+};
+
+use crate::{solution::Solution, AocError, Result};
+
+pub struct Day07;
+
+type Cards = [usize; 5];
+type Bid = usize;
+type Hand = (Cards, Bid);
+type Count<'a> = (usize, &'a usize);
+
+// Rank values for readability
+const FIVE_OF_A_KIND: usize = 7;
+const FOUR_OF_A_KIND: usize = 6;
+const FULL_HOUSE: usize = 5;
+const THREE_OF_A_KIND: usize = 4;
+const TWO_PAIR: usize = 3;
+const ONE_PAIR: usize = 2;
+const HIGH_CARD: usize = 1;
+
+/// `32T3K 765`
+fn parse_hand(input: &str) -> IResult<&str, (&str, Bid)> {
+    separated_pair(
+        recognize(count(one_of("AKQJT98765432"), 5)),
+        cut(space1),
+        cut(map(u32, |bid| bid as Bid)),
+    )(input)
+}
+
+fn card_values(hand: &str, joker: bool) -> Cards {
+    let mut cards = [0usize; 5];
+    for (card, c) in cards.iter_mut().zip(hand.chars()) {
+        *card = match c {
+            'A' => 14,
+            'K' => 13,
+            'Q' => 12,
+            'J' if joker => 0,
+            'J' => 11,
+            'T' => 10,
+            // The parser only lets digits 2 to 9 through
+            _ => c.to_digit(10).unwrap_or_default() as usize,
+        }
+    }
+    cards
+}
+
+fn high_card_frequency(x: Count, y: Count) -> Ordering {
+    let ((cnt_a, a), (cnt_b, b)) = (x, y);
+    if cnt_a == cnt_b {
+        return b.cmp(a);
+    }
+    cnt_b.cmp(&cnt_a)
+}
+
+fn get_hand_score(cards: &Cards, joker: bool) -> usize {
+    let initial_counts: Vec<Count> = cards
+        .iter()
+        // don't count jokers until we know their actual card value
+        .filter(|c| *c != &0usize)
+        .sorted()
+        .dedup_with_count()
+        // make sure in the case of a tie (two pair), we get the higher card value
+        .sorted_by(|a, b| high_card_frequency(*a, *b))
+        .collect();
+    let mut new_cards: Cards = [0; 5];
+    if initial_counts.is_empty() {
+        // All jokers, 5 of a kind
+        return 7;
+    }
+    let counts = if joker && cards.contains(&0) {
+        // Check for zeroes (jokers)
+        // if there are any, get most frequently occuring card
+        // replace zeroes with that card, redo the counts
+        let most_frequent = initial_counts[0].1;
+        cards.iter().enumerate().for_each(|(i, card_val)| {
+            new_cards[i] = if *card_val == 0 {
+                *most_frequent
+            } else {
+                *card_val
+            };
+        });
+        new_cards
+            .iter()
+            .sorted()
+            .dedup_with_count()
+            .sorted_by(|a, b| high_card_frequency(*a, *b))
+            .collect()
+    } else {
+        initial_counts
+    };
+    get_rank(&counts)
+}
+
+fn get_rank(counts: &[Count]) -> usize {
+    let top_card_count = counts[0].0;
+    let unique_cards = counts.len();
+    match unique_cards {
+        1 => FIVE_OF_A_KIND,
+        2 => match top_card_count {
+            4 => FOUR_OF_A_KIND,
+            3 => FULL_HOUSE,
+            _ => unreachable!(),
+        },
+        3 => {
+            if top_card_count == 3 {
+                THREE_OF_A_KIND
+            } else {
+                TWO_PAIR
+            }
+        }
+        4 => ONE_PAIR,
+        5 => HIGH_CARD,
+        _ => unreachable!(),
+    }
+}
+
+fn solve(input: &[(&str, Bid)], joker: bool) -> usize {
+    let hands = input
+        .iter()
+        .map(|&(hand, bid)| -> Hand { (card_values(hand, joker), bid) })
+        .sorted_by(|a, b| {
+            let a_score = get_hand_score(&a.0, joker);
+            let b_score = get_hand_score(&b.0, joker);
+            if a_score == b_score {
+                return a.0.cmp(&b.0);
+            }
+            a_score.cmp(&b_score)
+        });
+    hands.enumerate().map(|(i, (_, bid))| bid * (i + 1)).sum()
+}
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+    const EXAMPLES: &'static [&'static str] = &[SAMPLE_INPUT];
+    type Input<'a> = Vec<(&'a str, Bid)>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                all_consuming(parse_hand)(line)
+                    .map(|(_, hand)| hand)
+                    .map_err(|e| AocError::from_nom(Self::DAY, i + 1, line, e))
+            })
+            .collect()
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne> {
+        Ok(solve(input, false))
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo> {
+        Ok(solve(input, true))
+    }
+}
+
+const SAMPLE_INPUT: &str = "\
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+";
+
+crate::aoc_examples! {
+    solution: Day07,
+    input: SAMPLE_INPUT,
+    part1: 6440,
+    part2: 5905,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_edge_cases() {
+        let joker = true;
+        // should be 4 of a kind
+        assert_eq!(6, get_hand_score(&card_values("J42JJ", joker), joker));
+        // should be 5 of a kind
+        assert_eq!(7, get_hand_score(&card_values("JJJJJ", joker), joker));
+    }
+
+    #[test]
+    fn test_parse_error_position() {
+        let err = Day07::parse("32T3K 765\nT55X5 684").unwrap_err();
+        assert!(matches!(
+            err,
+            AocError::Parse {
+                day: 7,
+                line: 2,
+                column: 4,
+                ..
+            }
+        ));
+        let err = Day07::parse("32T3K 765\nKK677 28\nKTJJT bid").unwrap_err();
+        assert!(matches!(
+            err,
+            AocError::Parse {
+                line: 3,
+                column: 7,
+                ..
+            }
+        ));
+    }
+}
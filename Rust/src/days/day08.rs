@@ -0,0 +1,218 @@
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{alphanumeric1, char},
+    combinator::{all_consuming, cut, value},
+    multi::many1,
+    sequence::{delimited, separated_pair, terminated},
+    IResult,
+};
+
+use crate::{lcm, solution::Solution, AocError, Result};
+use std::collections::HashMap;
+
+pub struct Day08;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Next {
+    Left,
+    Right,
+}
+
+type NodeMap<'a> = HashMap<&'a str, (&'a str, &'a str)>;
+
+fn parse_instructions(input: &str) -> IResult<&str, Vec<Next>> {
+    many1(alt((
+        value(Next::Left, char('L')),
+        value(Next::Right, char('R')),
+    )))(input)
+}
+
+/// `AAA = (BBB, CCC)`
+fn parse_node(input: &str) -> IResult<&str, (&str, (&str, &str))> {
+    let (rest, key) = terminated(alphanumeric1, tag(" = "))(input)?;
+    let (rest, children) = cut(delimited(
+        char('('),
+        separated_pair(alphanumeric1, tag(", "), alphanumeric1),
+        char(')'),
+    ))(rest)?;
+    Ok((rest, (key, children)))
+}
+
+fn parse_input(input: &str) -> Result<(Vec<Next>, NodeMap<'_>)> {
+    let error = |line_no: usize, line: &str, e| AocError::from_nom(Day08::DAY, line_no, line, e);
+    let mut lines = input.lines();
+    let first = lines.next().unwrap_or_default();
+    let (_, instructions) =
+        all_consuming(parse_instructions)(first).map_err(|e| error(1, first, e))?;
+    // The nodes start after a blank line
+    if lines.next().is_some_and(|line| !line.is_empty()) {
+        return Err(AocError::Parse {
+            day: Day08::DAY,
+            line: 2,
+            column: 1,
+            message: "expected a blank line after the instructions".to_string(),
+        });
+    }
+    let mut node_map = HashMap::new();
+    for (i, line) in lines.enumerate() {
+        let (_, (key, children)) =
+            all_consuming(parse_node)(line).map_err(|e| error(i + 3, line, e))?;
+        node_map.insert(key, children);
+    }
+    Ok((instructions, node_map))
+}
+
+fn unsolvable(message: String) -> AocError {
+    AocError::Unsolvable {
+        day: Day08::DAY,
+        message,
+    }
+}
+
+fn traverse_map(start_node: &str, node_map: &NodeMap, instructions: &[Next]) -> Result<u64> {
+    // After this many steps some (node, instruction) pair has come up twice,
+    // so the walk is going round in circles
+    let limit = node_map.len() * instructions.len();
+    let mut node = start_node;
+    for (steps, next) in instructions.iter().cycle().enumerate().take(limit + 1) {
+        if node.ends_with('Z') {
+            return Ok(steps as u64);
+        }
+        let (left, right) = node_map
+            .get(node)
+            .ok_or_else(|| unsolvable(format!("node {node} is not defined")))?;
+        node = match next {
+            Next::Left => left,
+            Next::Right => right,
+        };
+    }
+    Err(unsolvable(format!(
+        "never reaches a node ending in Z from {start_node}"
+    )))
+}
+
+fn part_1(instructions: &[Next], node_map: &NodeMap) -> Result<u64> {
+    traverse_map("AAA", node_map, instructions)
+}
+
+fn part_2(instructions: &[Next], node_map: &NodeMap) -> Result<u64> {
+    let start_nodes = node_map
+        .keys()
+        .filter(|s| s.ends_with('A'))
+        .copied()
+        .collect::<Vec<&str>>();
+    if start_nodes.is_empty() {
+        return Err(unsolvable("no node ends in A".to_string()));
+    }
+    let all_path_lengths = start_nodes
+        .into_iter()
+        .map(|p| traverse_map(p, node_map, instructions))
+        .collect::<Result<Vec<u64>>>()?;
+    Ok(lcm(&all_path_lengths))
+}
+
+impl Solution for Day08 {
+    const DAY: u32 = 8;
+    const EXAMPLES: &'static [&'static str] = &[SAMPLE_INPUT];
+    type Input<'a> = (Vec<Next>, NodeMap<'a>);
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input)
+    }
+
+    fn part_one((instructions, node_map): &Self::Input<'_>) -> Result<Self::PartOne> {
+        part_1(instructions, node_map)
+    }
+
+    fn part_two((instructions, node_map): &Self::Input<'_>) -> Result<Self::PartTwo> {
+        part_2(instructions, node_map)
+    }
+}
+
+const SAMPLE_INPUT: &str = "\
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+
+crate::aoc_examples! {
+    solution: Day08,
+    input: SAMPLE_INPUT,
+    part1: 6,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        let (instructions, node_map) = parse_input(SAMPLE_INPUT).unwrap();
+        assert_eq!(instructions, vec![Next::Left, Next::Left, Next::Right]);
+        assert_eq!(node_map["BBB"], ("AAA", "ZZZ"));
+    }
+
+    #[test]
+    fn test_parse_error_position() {
+        let err = Day08::parse("LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA ZZZ)").unwrap_err();
+        assert!(matches!(
+            err,
+            AocError::Parse {
+                day: 8,
+                line: 4,
+                column: 11,
+                ..
+            }
+        ));
+        let err = Day08::parse("LXR\n\nAAA = (BBB, BBB)").unwrap_err();
+        assert!(matches!(
+            err,
+            AocError::Parse {
+                line: 1,
+                column: 2,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_missing_blank_line() {
+        let err = Day08::parse("LLR\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)").unwrap_err();
+        assert!(matches!(
+            err,
+            AocError::Parse {
+                day: 8,
+                line: 2,
+                column: 1,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_unsolvable_maps() {
+        let unsolvable = |input: &str, part_two: bool| {
+            let input = Day08::parse(input).unwrap();
+            let result = match part_two {
+                false => Day08::part_one(&input),
+                true => Day08::part_two(&input),
+            };
+            matches!(result, Err(AocError::Unsolvable { day: 8, .. }))
+        };
+        // AAA isn't defined
+        assert!(unsolvable("L\n\nBBB = (ZZZ, ZZZ)", false));
+        // CCC is reached but not defined
+        assert!(unsolvable("L\n\nAAA = (CCC, ZZZ)", false));
+        // Going round in circles without reaching ZZZ
+        assert!(unsolvable(
+            "LR\n\nAAA = (BBB, ZZZ)\nBBB = (BBB, AAA)",
+            false
+        ));
+        // No starting nodes at all
+        assert!(unsolvable("L\n\nBBB = (ZZZ, ZZZ)", true));
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{solution::Solution, Result};
+
+pub struct Day09;
+
+fn parse_seq(s: &str) -> Vec<i32> {
+    s.split_whitespace()
+        .map(|d| d.parse::<i32>().unwrap())
+        .collect()
+}
+
+fn differences(seq: &[i32]) -> Vec<i32> {
+    (1..seq.len()).map(|idx| seq[idx] - seq[idx - 1]).collect()
+}
+
+fn get_back_value(seq: &[i32]) -> i32 {
+    let last = seq.last().unwrap_or(&0);
+    if seq.iter().all(|d| d == last) {
+        *last
+    } else {
+        last + get_back_value(&differences(seq))
+    }
+}
+
+fn get_front_value(seq: &[i32]) -> i32 {
+    let first = seq.first().unwrap_or(&0);
+    if seq.iter().all(|d| d == first) {
+        *first
+    } else {
+        first - get_front_value(&differences(seq))
+    }
+}
+
+fn part_1(input: &str) -> i32 {
+    input
+        .lines()
+        .map(|line| get_back_value(&parse_seq(line)))
+        .sum()
+}
+
+fn part_2(input: &str) -> i32 {
+    input
+        .lines()
+        .map(|line| get_front_value(&parse_seq(line)))
+        .sum()
+}
+
+impl Solution for Day09 {
+    const DAY: u32 = 9;
+    const EXAMPLES: &'static [&'static str] = &[SAMPLE];
+    type Input<'a> = &'a str;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne> {
+        Ok(part_1(input))
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo> {
+        Ok(part_2(input))
+    }
+}
+
+const SAMPLE: &str = "\
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45 
+";
+
+crate::aoc_examples! {
+    solution: Day09,
+    input: SAMPLE,
+    part1: 114,
+    part2: 2,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let seq = "0 3 6 9 12 15";
+        assert_eq!(parse_seq(seq), vec![0, 3, 6, 9, 12, 15]);
+    }
+
+    #[test]
+    fn test_differences() {
+        let seq = parse_seq("0 3 6 9 12 15");
+        assert_eq!(differences(&seq), vec![3, 3, 3, 3, 3]);
+    }
+
+    #[test]
+    fn test_next_back() {
+        let seq = parse_seq("0 3 6 9 12 15");
+        assert_eq!(get_back_value(&seq), 18);
+    }
+}
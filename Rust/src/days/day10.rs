@@ -0,0 +1,278 @@
+use colored::Colorize;
+
+use crate::{geometry, grid::Grid2D, point::Point, solution::Solution, AocError, Result};
+
+pub struct Day10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pipe {
+    Start,
+    Empty,
+    NorthSouth,
+    EastWest,
+    NorthWest,
+    NorthEast,
+    SouthEast,
+    SouthWest,
+}
+
+impl TryFrom<char> for Pipe {
+    type Error = String;
+
+    fn try_from(value: char) -> std::result::Result<Self, Self::Error> {
+        Ok(match value {
+            '|' => Pipe::NorthSouth,
+            '-' => Pipe::EastWest,
+            'L' => Pipe::NorthEast,
+            'J' => Pipe::NorthWest,
+            '7' => Pipe::SouthWest,
+            'F' => Pipe::SouthEast,
+            'S' => Pipe::Start,
+            '.' => Pipe::Empty,
+            _ => return Err(format!("invalid character: {value:?}")),
+        })
+    }
+}
+
+fn unsolvable(message: String) -> AocError {
+    AocError::Unsolvable {
+        day: Day10::DAY,
+        message,
+    }
+}
+
+/// Step from `cur_pos` towards `dir` into the next pipe and find which way it
+/// leads. None once the loop is back at the start.
+fn get_next_pos(
+    grid: &Grid2D<Pipe>,
+    cur_pos: Point,
+    dir: Direction,
+) -> Result<Option<(Point, Direction)>> {
+    let offset = match dir {
+        Direction::North => (0, -1),
+        Direction::South => (0, 1),
+        Direction::East => (1, 0),
+        Direction::West => (-1, 0),
+    };
+    let next_pt = cur_pos + offset.into();
+    let next_pipe = grid
+        .get_ref(next_pt)
+        .ok_or_else(|| unsolvable(format!("the loop runs off the grid at {next_pt}")))?;
+    use Direction::*;
+    use Pipe::*;
+    let new_dir = match (dir, next_pipe) {
+        (_, Start) => return Ok(None),
+        (North, NorthSouth) | (East, NorthWest) | (West, NorthEast) => North,
+        (South, NorthSouth) | (East, SouthWest) | (West, SouthEast) => South,
+        (East, EastWest) | (North, SouthEast) | (South, NorthEast) => East,
+        (West, EastWest) | (North, SouthWest) | (South, NorthWest) => West,
+        _ => {
+            return Err(unsolvable(format!(
+                "pipe {next_pipe:?} at {next_pt} can't connect"
+            )))
+        }
+    };
+    Ok(Some((next_pt, new_dir)))
+}
+
+/// Pick a direction out of the start tile whose neighboring pipe connects back to it
+fn start_direction(grid: &Grid2D<Pipe>, start_pt: Point) -> Direction {
+    let connects = |offset: (i64, i64), pipes: [Pipe; 3]| {
+        grid.get_ref(start_pt + offset.into())
+            .is_some_and(|pipe| pipes.contains(pipe))
+    };
+    use Pipe::*;
+    if connects((0, -1), [NorthSouth, SouthEast, SouthWest]) {
+        Direction::North
+    } else if connects((1, 0), [EastWest, NorthWest, SouthWest]) {
+        Direction::East
+    } else if connects((0, 1), [NorthSouth, NorthEast, NorthWest]) {
+        Direction::South
+    } else {
+        Direction::West
+    }
+}
+
+/// Tailored this to my puzzle input for brevity's sake
+fn follow_loop(
+    grid: &Grid2D<Pipe>,
+    start_pt: Point,
+    direction: Direction,
+    loop_vertices: &mut Vec<Point>,
+) -> Result<usize> {
+    let mut count = 1;
+    let (mut next_pt, mut next_dir) = (start_pt, direction);
+    while let Some((pos, dir)) = get_next_pos(grid, next_pt, next_dir)? {
+        next_pt = pos;
+        loop_vertices.push(next_pt);
+        next_dir = dir;
+        count += 1;
+    }
+    Ok(count)
+}
+
+fn find_start(grid: &Grid2D<Pipe>) -> Result<Point> {
+    grid.find_item_coord(&Pipe::Start)
+        .ok_or_else(|| unsolvable("no start tile (S)".to_string()))
+}
+
+#[allow(dead_code)]
+fn print_grid(grid: &Grid2D<Pipe>, color_pipe_positions: &[Point]) {
+    let mut output = String::new();
+    for y in 0..grid.height {
+        for x in 0..grid.width {
+            let pt = Point::new(x, y);
+            let pipe = grid.get_ref(pt).unwrap();
+            let pipe_symbol = match pipe {
+                // Pipe::NorthSouth => "║",
+                // Pipe::EastWest => "═",
+                // Pipe::NorthEast => "╚",
+                // Pipe::NorthWest => "╝",
+                // Pipe::SouthWest => "╗",
+                // Pipe::SouthEast => "╔",
+                // Pipe::Start => "█",
+                // Pipe::Empty => ".",
+                Pipe::NorthSouth => "│",
+                Pipe::EastWest => "─",
+                Pipe::NorthEast => "└",
+                Pipe::NorthWest => "┘",
+                Pipe::SouthWest => "┐",
+                Pipe::SouthEast => "┌",
+                Pipe::Start => "█",
+                Pipe::Empty => "☻",
+            };
+            if color_pipe_positions.contains(&pt) {
+                output.push_str(format!("{}", pipe_symbol.red()).as_str());
+            } else {
+                output.push_str(pipe_symbol);
+            };
+        }
+        output.push('\n');
+    }
+    println!("{output}");
+}
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    const EXAMPLES: &'static [&'static str] = &[SAMPLE, LARGE_SAMPLE, ANOTHER_SAMPLE];
+    type Input<'a> = Grid2D<Pipe>;
+    type PartOne = usize;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        input.parse().map_err(|e: AocError| e.for_day(Self::DAY))
+    }
+
+    fn part_one(grid: &Self::Input<'_>) -> Result<Self::PartOne> {
+        let start_pt = find_start(grid)?;
+        let init_direction = start_direction(grid, start_pt);
+        let mut loop_vertices = vec![start_pt];
+        Ok(follow_loop(grid, start_pt, init_direction, &mut loop_vertices)? / 2)
+    }
+
+    fn part_two(grid: &Self::Input<'_>) -> Result<Self::PartTwo> {
+        let start_pt = find_start(grid)?;
+        let init_direction = start_direction(grid, start_pt);
+        // Vec to contain vertices as they are found
+        let mut loop_vertices = vec![start_pt];
+        follow_loop(grid, start_pt, init_direction, &mut loop_vertices)?;
+        // Every tile of the loop is a vertex, so the tiles enclosed by it are
+        // the polygon's interior lattice points
+        Ok(geometry::interior_points(&loop_vertices))
+    }
+}
+
+const SAMPLE: &str = "\
+..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...
+";
+
+const LARGE_SAMPLE: &str = "\
+.F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...";
+
+const ANOTHER_SAMPLE: &str = "\
+FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L";
+
+crate::aoc_examples! {
+    solution: Day10,
+    examples: {
+        sample { input: SAMPLE, part1: 8 },
+        large_sample { input: LARGE_SAMPLE, part2: 8 },
+        another_sample { input: ANOTHER_SAMPLE, part2: 10 },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_follow_loop() {
+        let grid = Day10::parse(SAMPLE).unwrap();
+        let start_pt = grid.find_item_coord(&Pipe::Start).unwrap();
+        let mut positions = vec![];
+        assert_eq!(
+            follow_loop(&grid, start_pt, Direction::South, &mut positions).unwrap(),
+            16
+        );
+        // Every tile visited after leaving the start
+        assert_eq!(positions.len(), 15);
+        assert_eq!(start_direction(&grid, start_pt), Direction::East);
+    }
+
+    #[test]
+    fn test_broken_loops() {
+        let unsolvable = |input: &str| {
+            let grid = Day10::parse(input).unwrap();
+            matches!(
+                Day10::part_one(&grid),
+                Err(AocError::Unsolvable { day: 10, .. })
+            )
+        };
+        // No start tile
+        assert!(unsolvable("F7\nLJ"));
+        // The pipe leads off the edge of the grid
+        assert!(unsolvable("S-\n.."));
+        // The pipe ends in a tile that doesn't connect back
+        assert!(unsolvable("S-7\n..|\n..."));
+        assert!(unsolvable("S-|\n..."));
+    }
+
+    #[test]
+    fn test_color() {
+        let grid = Day10::parse(LARGE_SAMPLE).unwrap();
+        let mut positions: Vec<Point> = vec![(12, 4).into()];
+        let start_pt: Point = Point::new(12, 4);
+        let _ = follow_loop(&grid, start_pt, Direction::East, &mut positions);
+        print_grid(&grid, &positions);
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{compress::WeightedAxis, grid::Grid2D, point::Point, solution::Solution, Result};
+
+pub struct Day11;
+
+fn expand_galactic_map(grid: &Grid2D<char>, expansion_factor: i64) -> Vec<Point> {
+    // empty rows & columns each count as `expansion_factor` of them
+    let weight = |empty: bool| if empty { expansion_factor } else { 1 };
+    let xs = WeightedAxis::new(
+        grid.columns()
+            .map(|mut column| weight(column.all(|c| *c == '.'))),
+    );
+    let ys = WeightedAxis::new(grid.rows().map(|row| weight(row.iter().all(|c| *c == '.'))));
+    grid.find_all('#')
+        .into_iter()
+        .filter_map(|pt| Some(Point::new(xs.to_real(pt.x)?, ys.to_real(pt.y)?)))
+        .collect()
+}
+
+fn sum_shortest_distances(points: &[Point]) -> i64 {
+    let mut total = 0;
+    for (i, pt1) in points.iter().enumerate() {
+        for pt2 in &points[i + 1..] {
+            total += pt1.manhattan_distance(pt2);
+        }
+    }
+    total
+}
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    const EXAMPLES: &'static [&'static str] = &[INPUT];
+    type Input<'a> = Grid2D<char>;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input.into())
+    }
+
+    fn part_one(grid: &Self::Input<'_>) -> Result<Self::PartOne> {
+        Ok(sum_shortest_distances(&expand_galactic_map(grid, 2)))
+    }
+
+    fn part_two(grid: &Self::Input<'_>) -> Result<Self::PartTwo> {
+        Ok(sum_shortest_distances(&expand_galactic_map(
+            grid, 1_000_000,
+        )))
+    }
+}
+
+const INPUT: &str = "\
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+crate::aoc_examples! {
+    solution: Day11,
+    input: INPUT,
+    part1: 374,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part2() {
+        let grid: Grid2D<char> = Grid2D::from(INPUT);
+        let galaxy_pts = expand_galactic_map(&grid, 10);
+        assert_eq!(sum_shortest_distances(&galaxy_pts), 1030);
+        let galaxy_pts_2 = expand_galactic_map(&grid, 100);
+        assert_eq!(sum_shortest_distances(&galaxy_pts_2), 8410);
+    }
+}
@@ -0,0 +1,176 @@
+use nom::{
+    branch::alt,
+    character::complete::{char, space1, u32},
+    combinator::{all_consuming, cut, map, value},
+    multi::{many1, separated_list1},
+    sequence::separated_pair,
+    IResult,
+};
+
+use crate::{solution::Solution, AocError, Result};
+
+pub struct Day12;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Spring {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+/// Condition record of a row and the sizes of its groups of damaged springs
+type Row = (Vec<Spring>, Vec<usize>);
+
+fn parse_spring(input: &str) -> IResult<&str, Spring> {
+    alt((
+        value(Spring::Operational, char('.')),
+        value(Spring::Damaged, char('#')),
+        value(Spring::Unknown, char('?')),
+    ))(input)
+}
+
+fn parse_row(input: &str) -> IResult<&str, Row> {
+    separated_pair(
+        many1(parse_spring),
+        space1,
+        cut(separated_list1(char(','), cut(map(u32, |n| n as usize)))),
+    )(input)
+}
+
+/// Number of ways to fill in the unknown springs so the damaged ones form
+/// exactly `groups`.
+///
+/// `ways[i][j]` counts the arrangements of `springs[i..]` into `groups[j..]`
+/// and only depends on later springs, so the table is filled back to front.
+fn count_arrangements(springs: &[Spring], groups: &[usize]) -> u64 {
+    let (n, m) = (springs.len(), groups.len());
+    let mut ways = vec![vec![0u64; m + 1]; n + 2];
+    ways[n][m] = 1;
+    ways[n + 1][m] = 1;
+    for i in (0..n).rev() {
+        for j in (0..=m).rev() {
+            let mut count = 0;
+            // Leave this spring working and move on
+            if springs[i] != Spring::Damaged {
+                count += ways[i + 1][j];
+            }
+            // Start the next group here; it must fit and be followed by a
+            // working spring (or the end of the row), which it also consumes
+            if springs[i] != Spring::Operational && j < m {
+                let end = i + groups[j];
+                if end <= n
+                    && springs[i..end].iter().all(|s| *s != Spring::Operational)
+                    && springs.get(end) != Some(&Spring::Damaged)
+                {
+                    count += ways[end + 1][j + 1];
+                }
+            }
+            ways[i][j] = count;
+        }
+    }
+    ways[0][0]
+}
+
+/// Replace the row with five copies of itself, the springs separated by `?`
+fn unfold((springs, groups): &Row) -> Row {
+    let mut unfolded = springs.clone();
+    for _ in 0..4 {
+        unfolded.push(Spring::Unknown);
+        unfolded.extend(springs);
+    }
+    (unfolded, groups.repeat(5))
+}
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+    const EXAMPLES: &'static [&'static str] = &[SAMPLE];
+    type Input<'a> = Vec<Row>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                all_consuming(parse_row)(line)
+                    .map(|(_, row)| row)
+                    .map_err(|e| AocError::from_nom(Self::DAY, i + 1, line, e))
+            })
+            .collect()
+    }
+
+    fn part_one(rows: &Self::Input<'_>) -> Result<Self::PartOne> {
+        Ok(rows
+            .iter()
+            .map(|(springs, groups)| count_arrangements(springs, groups))
+            .sum())
+    }
+
+    fn part_two(rows: &Self::Input<'_>) -> Result<Self::PartTwo> {
+        Ok(rows
+            .iter()
+            .map(unfold)
+            .map(|(springs, groups)| count_arrangements(&springs, &groups))
+            .sum())
+    }
+}
+
+const SAMPLE: &str = "\
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+
+crate::aoc_examples! {
+    solution: Day12,
+    input: SAMPLE,
+    part1: 21,
+    part2: 525152,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_count_per_row() {
+        let rows = Day12::parse(SAMPLE).unwrap();
+        let counts: Vec<u64> = rows
+            .iter()
+            .map(|(springs, groups)| count_arrangements(springs, groups))
+            .collect();
+        assert_eq!(counts, vec![1, 4, 1, 1, 4, 10]);
+        let unfolded: Vec<u64> = rows
+            .iter()
+            .map(unfold)
+            .map(|(springs, groups)| count_arrangements(&springs, &groups))
+            .collect();
+        assert_eq!(unfolded, vec![1, 16384, 1, 16, 2500, 506250]);
+    }
+
+    #[test]
+    fn test_unfold() {
+        let (_, row) = parse_row(".# 1").unwrap();
+        let (springs, groups) = unfold(&row);
+        assert_eq!(springs.len(), 14);
+        assert_eq!(springs[2], Spring::Unknown);
+        assert_eq!(groups, vec![1; 5]);
+    }
+
+    #[test]
+    fn test_parse_error_column() {
+        let err = Day12::parse("???.### 1,1,3\n.??..??. 1,x").unwrap_err();
+        assert!(matches!(
+            err,
+            AocError::Parse {
+                day: 12,
+                line: 2,
+                column: 12,
+                ..
+            }
+        ));
+    }
+}
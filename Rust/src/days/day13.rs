@@ -0,0 +1,266 @@
+use crate::{solution::Solution, AocError, Result};
+
+pub struct Day13;
+
+/// A row or column as a bitset, `#` being a set bit. Each word holds up to 64
+/// cells, the first cell of the word in its most significant used bit.
+type Line = Vec<u64>;
+
+/// Row and column bitsets of one pattern
+type Pattern = (Vec<Line>, Vec<Line>);
+
+/// Shift the next cell into the bitset, starting a new word every 64 cells
+fn push_bit(line: &mut Line, index: usize, bit: bool) {
+    if index.is_multiple_of(64) {
+        line.push(0);
+    }
+    let word = line.last_mut().unwrap();
+    *word = *word << 1 | bit as u64;
+}
+
+/// Encode every row and every column of a pattern as a bitset. `first_line` is
+/// the 1-based line of the input the pattern starts on, for error messages.
+fn parse_grid_to_bin(lines: &[&str], first_line: usize) -> Result<Pattern> {
+    let width = lines.first().map_or(0, |line| line.len());
+    let mut rows = vec![];
+    let mut cols = vec![vec![]; width];
+    for (y, line) in lines.iter().enumerate() {
+        let error = |column: usize, message: String| AocError::Parse {
+            day: Day13::DAY,
+            line: first_line + y,
+            column,
+            message,
+        };
+        if line.len() != width {
+            let message = format!("row is {} wide, expected {width}", line.len());
+            return Err(error(line.len().min(width) + 1, message));
+        }
+        let mut cur_row = vec![];
+        for (x, c) in line.chars().enumerate() {
+            let bit = match c {
+                '#' => true,
+                '.' => false,
+                _ => return Err(error(x + 1, format!("unrecognized character {c:?}"))),
+            };
+            push_bit(&mut cur_row, x, bit);
+            push_bit(&mut cols[x], y, bit);
+        }
+        rows.push(cur_row);
+    }
+    Ok((rows, cols))
+}
+
+/// Find a line of reflection between `lines[i - 1]` and `lines[i]` where the
+/// mirrored lines differ in exactly `smudges` bits (Hamming distance), and
+/// return `i`, the number of lines before it
+fn find_reflection(lines: &[Line], smudges: u32) -> Option<usize> {
+    (1..lines.len()).find(|&i| {
+        let (before, after) = lines.split_at(i);
+        before
+            .iter()
+            .rev()
+            .zip(after)
+            .flat_map(|(a, b)| a.iter().zip(b).map(|(a, b)| (a ^ b).count_ones()))
+            .sum::<u32>()
+            == smudges
+    })
+}
+
+/// 100 per row above a horizontal line of reflection, or 1 per column left of
+/// a vertical one
+fn summarize(pattern: &Pattern, smudges: u32) -> Option<usize> {
+    let (rows, cols) = pattern;
+    match find_reflection(rows, smudges) {
+        Some(idx) => Some(100 * idx),
+        None => find_reflection(cols, smudges),
+    }
+}
+
+fn solve(patterns: &[Pattern], smudges: u32) -> Result<usize> {
+    patterns
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            summarize(p, smudges).ok_or_else(|| AocError::Unsolvable {
+                day: Day13::DAY,
+                message: format!("no mirror found in pattern {}", i + 1),
+            })
+        })
+        .sum()
+}
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+    const EXAMPLES: &'static [&'static str] = &[SAMPLE_2];
+    type Input<'a> = Vec<Pattern>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        // Patterns are separated by blank lines, and one more blank line at
+        // the end closes the last pattern. `lines` also drops the `\r` of CRLF
+        // line endings.
+        let mut patterns = vec![];
+        let mut block: Vec<&str> = vec![];
+        let mut first_line = 1;
+        for (i, line) in input.lines().chain([""]).enumerate() {
+            if !line.trim().is_empty() {
+                if block.is_empty() {
+                    first_line = i + 1;
+                }
+                block.push(line);
+            } else if !block.is_empty() {
+                patterns.push(parse_grid_to_bin(&block, first_line)?);
+                block.clear();
+            }
+        }
+        Ok(patterns)
+    }
+
+    fn part_one(patterns: &Self::Input<'_>) -> Result<Self::PartOne> {
+        solve(patterns, 0)
+    }
+
+    fn part_two(patterns: &Self::Input<'_>) -> Result<Self::PartTwo> {
+        solve(patterns, 1)
+    }
+}
+
+const SAMPLE_2: &str = "\
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+
+crate::aoc_examples! {
+    solution: Day13,
+    input: SAMPLE_2,
+    part1: 405,
+    part2: 400,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SAMPLE_1: &str = "\
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.";
+
+    fn pattern(input: &str) -> Result<Pattern> {
+        parse_grid_to_bin(&input.lines().collect::<Vec<_>>(), 1)
+    }
+
+    #[test]
+    fn test_parse() {
+        let (rows, cols) = pattern(SAMPLE_1).unwrap();
+        let words = |lines: Vec<Line>| lines.concat();
+        assert_eq!(words(rows), vec![358, 90, 385, 385, 90, 102, 346]);
+        assert_eq!(words(cols), vec![89, 24, 103, 66, 37, 37, 66, 103, 24]);
+    }
+
+    #[test]
+    fn test_find_reflection() {
+        let (rows, cols) = pattern(SAMPLE_1).unwrap();
+        assert_eq!(find_reflection(&cols, 0), Some(5));
+        assert_eq!(find_reflection(&rows, 0), None);
+        // Fixing the smudge in the top left corner gives a horizontal line
+        assert_eq!(find_reflection(&rows, 1), Some(3));
+    }
+
+    #[test]
+    fn test_wide_pattern() {
+        // 40 columns mirrored down the middle, most of them empty
+        let half = "#.##..##..#.#...#.##";
+        let row = format!("{half}{}", half.chars().rev().collect::<String>());
+        let pattern = pattern(&format!("{row}\n{}", ".".repeat(40))).unwrap();
+        assert_eq!(pattern.1.len(), 40);
+        assert_eq!(summarize(&pattern, 0), Some(20));
+    }
+
+    #[test]
+    fn test_wider_than_a_word() {
+        // Two 150 wide rows that only differ in column 10, which a single
+        // 64-bit mask would have shifted out
+        let row: String = (0..150)
+            .map(|i| if i % 7 < 3 { '#' } else { '.' })
+            .collect();
+        let mut smudged = row.clone().into_bytes();
+        smudged[10] = if smudged[10] == b'#' { b'.' } else { b'#' };
+        let smudged = String::from_utf8(smudged).unwrap();
+        let (rows, cols) = pattern(&format!("{row}\n{smudged}")).unwrap();
+        assert_eq!(rows[0].len(), 3);
+        assert_eq!(cols.len(), 150);
+        assert_eq!(find_reflection(&rows, 0), None);
+        assert_eq!(find_reflection(&rows, 1), Some(1));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let input = format!("{SAMPLE_1}\n\n#.#\n#x#");
+        let err = Day13::parse(&input).unwrap_err();
+        assert!(matches!(
+            err,
+            AocError::Parse {
+                day: 13,
+                line: 10,
+                column: 2,
+                ..
+            }
+        ));
+        let err = Day13::parse("#.#\n#.").unwrap_err();
+        assert!(matches!(
+            err,
+            AocError::Parse {
+                line: 2,
+                column: 3,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_no_mirror() {
+        let patterns = Day13::parse("#.\n..").unwrap();
+        assert!(matches!(
+            Day13::part_one(&patterns),
+            Err(AocError::Unsolvable { day: 13, .. })
+        ));
+    }
+
+    #[test]
+    fn test_blank_lines() {
+        let patterns = Day13::parse(&format!("{SAMPLE_2}\n")).unwrap();
+        assert_eq!(patterns.len(), 2);
+        assert_eq!(Day13::part_one(&patterns).unwrap(), 405);
+        // Windows line endings and extra blank lines around the patterns
+        let crlf = format!("\n{}\n\n", SAMPLE_2.replace("\n\n", "\n\n\n")).replace('\n', "\r\n");
+        assert_eq!(Day13::parse(&crlf).unwrap(), patterns);
+        // Line numbers in errors still count the blank lines
+        let err = Day13::parse(&format!("\n{SAMPLE_2}\n\n\n#.#\n#x#")).unwrap_err();
+        assert!(matches!(
+            err,
+            AocError::Parse {
+                line: 20,
+                column: 2,
+                ..
+            }
+        ));
+    }
+}
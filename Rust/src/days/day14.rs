@@ -0,0 +1,151 @@
+use crate::{cycle::find_cycle, grid::Grid2D, solution::Solution, Result};
+
+pub struct Day14;
+
+const SPIN_CYCLES: usize = 1_000_000_000;
+
+/// Roll every round rock (`O`) north until it hits a cube rock (`#`), another
+/// round rock or the edge of the platform
+fn tilt_north(grid: &mut Grid2D<char>) {
+    for x in 0..grid.width {
+        let mut free_y = 0;
+        for y in 0..grid.height {
+            match grid.get_ref((x, y).into()) {
+                Some('#') => free_y = y + 1,
+                Some('O') => {
+                    grid.set((x, y).into(), '.');
+                    grid.set((x, free_y).into(), 'O');
+                    free_y += 1;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Tilt north, west, south and east. Rotating clockwise after each tilt
+/// brings the next direction to the top, and the grid back to how it started.
+fn spin_cycle(grid: &Grid2D<char>) -> Grid2D<char> {
+    let mut grid = grid.clone();
+    for _ in 0..4 {
+        tilt_north(&mut grid);
+        grid.rotate_cw();
+    }
+    grid
+}
+
+/// Total load on the north support beams
+fn get_load(grid: &Grid2D<char>) -> i64 {
+    grid.find_all('O').iter().map(|p| grid.height - p.y).sum()
+}
+
+fn part_1(grid: &Grid2D<char>) -> i64 {
+    let mut grid = grid.clone();
+    tilt_north(&mut grid);
+    get_load(&grid)
+}
+
+fn part_2(grid: &Grid2D<char>) -> i64 {
+    let (cycle, states) = find_cycle(grid.clone(), spin_cycle);
+    get_load(&states[cycle.equivalent_step(SPIN_CYCLES)])
+}
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+    const EXAMPLES: &'static [&'static str] = &[SAMPLE];
+    type Input<'a> = Grid2D<char>;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(input.into())
+    }
+
+    fn part_one(grid: &Self::Input<'_>) -> Result<Self::PartOne> {
+        Ok(part_1(grid))
+    }
+
+    fn part_two(grid: &Self::Input<'_>) -> Result<Self::PartTwo> {
+        Ok(part_2(grid))
+    }
+}
+
+const SAMPLE: &str = "\
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+crate::aoc_examples! {
+    solution: Day14,
+    input: SAMPLE,
+    part1: 136,
+    part2: 64,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const AFTER_ONE_CYCLE: &str = "\
+.....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#....";
+
+    const TILTED_NORTH: &str = "\
+OOOO.#.O..
+OO..#....#
+OO..O##..O
+O..#.OO...
+........#.
+..#....#.#
+..O..#.O.O
+..O.......
+#....###..
+#....#....";
+
+    #[test]
+    fn test_parse_input() {
+        let grid = Day14::parse(SAMPLE).unwrap();
+        assert_eq!((grid.width, grid.height), (10, 10));
+        assert_eq!(grid.find_all('O').len(), 18);
+        assert_eq!(grid.find_all('#').len(), 17);
+        assert_eq!(grid.to_string(), format!("{SAMPLE}\n"));
+    }
+
+    #[test]
+    fn test_tilt_up() {
+        let mut grid = Day14::parse(SAMPLE).unwrap();
+        tilt_north(&mut grid);
+        assert_eq!(grid, TILTED_NORTH.into());
+        assert_eq!(get_load(&grid), 136);
+        // Already tilted rocks stay put
+        tilt_north(&mut grid);
+        assert_eq!(grid, TILTED_NORTH.into());
+    }
+
+    #[test]
+    fn test_spin_cycle() {
+        let grid: Grid2D<char> = SAMPLE.into();
+        assert_eq!(spin_cycle(&grid), AFTER_ONE_CYCLE.into());
+    }
+
+    #[test]
+    fn test_cycle() {
+        let (cycle, _) = find_cycle(SAMPLE.into(), spin_cycle);
+        assert_eq!((cycle.mu, cycle.lambda), (3, 7));
+    }
+}
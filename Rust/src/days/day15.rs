@@ -0,0 +1,195 @@
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{alpha1, u8},
+    combinator::{all_consuming, cut, map},
+    multi::separated_list1,
+    sequence::preceded,
+    IResult,
+};
+use std::collections::HashMap;
+
+use crate::{solution::Solution, AocError, Result};
+
+pub struct Day15;
+
+type LensBoxes<'a> = HashMap<u8, Vec<(&'a str, u8)>>;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Operation<'a> {
+    FocalLength(u8),
+    Remove(&'a str),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct BoxInst<'a> {
+    label: &'a str,
+    box_id: u8,
+    op: Operation<'a>,
+}
+
+/// Example:
+/// ```text
+/// "qp=3" -> BoxInst { label: "qp", box_id: 1, op: Operation::FocalLength(3) }
+/// ```
+fn parse_box(input: &str) -> IResult<&str, BoxInst<'_>> {
+    let (rest, label) = alpha1(input)?;
+    let (rest, op) = alt((
+        map(preceded(tag("="), u8), Operation::FocalLength),
+        map(tag("-"), |_| Operation::Remove(label)),
+    ))(rest)?;
+    let box_id = hash(label).try_into().unwrap();
+    Ok((rest, BoxInst { label, box_id, op }))
+}
+
+/// Parse the comma-separated initialization sequence (a single line)
+fn parse_steps(input: &str) -> Result<Vec<BoxInst<'_>>> {
+    all_consuming(separated_list1(tag(","), cut(parse_box)))(input)
+        .map(|(_, steps)| steps)
+        .map_err(|e| AocError::from_nom(Day15::DAY, 1, input, e))
+}
+
+fn hash(string: &str) -> usize {
+    let mut total = 0;
+    for c in string.chars() {
+        total = ((total + c as usize) * 17) % 256;
+    }
+    total
+}
+
+fn part_1(input: &str) -> usize {
+    input.split(',').map(hash).sum()
+}
+
+/// Here we use the `position` method of `Vec` to figure out whether & where
+/// to insert (or replace) lenses into a box. Removal uses the `remove` method
+/// which is similar.
+fn part_2<'a>(box_instructions: &[BoxInst<'a>], lens_boxes: &mut LensBoxes<'a>) -> usize {
+    for inst in box_instructions {
+        // println!("processing: {inst:?}");
+        let BoxInst { label, box_id, op } = *inst;
+        match op {
+            // Check if this box has had anything in it yet
+            Operation::FocalLength(focal_len) => match lens_boxes.get_mut(&box_id) {
+                // if it has, search the box for the label & focal_len
+                Some(entry) => {
+                    match entry
+                        .iter()
+                        .map(|(label, _)| label)
+                        .position(|l| l == &label)
+                    {
+                        // Replace it if it exists
+                        Some(idx) => entry[idx] = (label, focal_len),
+                        // Add it to the end of the exisiting `Vec`
+                        None => entry.push((label, focal_len)),
+                    }
+                }
+                // If there's nothing in this box yet, create it with the (label, focal_len)
+                None => {
+                    lens_boxes.insert(box_id, vec![(label, focal_len)]);
+                }
+            },
+            Operation::Remove(label) => {
+                // Same process as above, find the label and if it exists remove it
+                // from the `Vec`
+                if let Some(lenses) = lens_boxes.get_mut(&box_id) {
+                    if let Some(idx) = lenses
+                        .iter()
+                        .map(|(label, _)| label)
+                        .position(|l| l == &label)
+                    {
+                        _ = lenses.remove(idx);
+                    }
+                }
+            }
+        }
+    }
+    let mut total = 0usize;
+    for (box_id, lens_box) in lens_boxes.iter() {
+        total += lens_box
+            .iter()
+            .enumerate()
+            .map(|(i, (_l, focal_len))| (*box_id as usize + 1) * (i + 1) * *focal_len as usize)
+            .sum::<usize>()
+    }
+    total
+}
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+    const EXAMPLES: &'static [&'static str] = &[SAMPLE];
+    /// Raw sequence (hashed as-is for part 1) and the parsed steps
+    type Input<'a> = (&'a str, Vec<BoxInst<'a>>);
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        // have to remove newline characters
+        let input = input.trim_end_matches('\n');
+        Ok((input, parse_steps(input)?))
+    }
+
+    fn part_one((input, _): &Self::Input<'_>) -> Result<Self::PartOne> {
+        Ok(part_1(input))
+    }
+
+    fn part_two((_, steps): &Self::Input<'_>) -> Result<Self::PartTwo> {
+        let mut lens_boxes: LensBoxes = HashMap::new();
+        Ok(part_2(steps, &mut lens_boxes))
+    }
+}
+
+const SAMPLE: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+crate::aoc_examples! {
+    solution: Day15,
+    input: SAMPLE,
+    part1: 1320,
+    part2: 145,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(hash("HASH"), 52);
+    }
+
+    #[test]
+    fn test_parse_box() {
+        let boxes = parse_steps(SAMPLE).unwrap();
+        assert_eq!(boxes.len(), 11);
+        assert_eq!(
+            boxes[0],
+            BoxInst {
+                label: "rn",
+                box_id: 0,
+                op: Operation::FocalLength(1)
+            }
+        );
+        assert_eq!(
+            boxes[4],
+            BoxInst {
+                label: "qp",
+                box_id: 1,
+                op: Operation::Remove("qp")
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_error_column() {
+        let err = parse_steps("rn=1,cm-,qp?3").unwrap_err();
+        assert!(matches!(
+            err,
+            AocError::Parse {
+                day: 15,
+                line: 1,
+                column: 12,
+                ..
+            }
+        ));
+    }
+}
@@ -0,0 +1,168 @@
+use crate::{
+    grid::Grid2D,
+    point::{Direction, Point},
+    search::a_star,
+    solution::Solution,
+    AocError, Result,
+};
+
+pub struct Day17;
+
+/// Where the crucible is, which way it's heading and how many blocks it has
+/// moved in that direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Crucible {
+    pos: Point,
+    dir: Direction,
+    run: u8,
+}
+
+fn parse_input(input: &str) -> Result<Grid2D<u8>> {
+    Grid2D::parse_with(input, |point, c| {
+        c.to_digit(10).map(|d| d as u8).ok_or(AocError::Grid {
+            point,
+            message: format!("expected a digit, found {c:?}"),
+        })
+    })
+}
+
+/// Moves available to a crucible that must go at least `min_run` blocks before
+/// turning (or stopping) and at most `max_run` blocks in a straight line
+fn next_moves(
+    grid: &Grid2D<u8>,
+    crucible: &Crucible,
+    min_run: u8,
+    max_run: u8,
+) -> Vec<(Crucible, usize)> {
+    let mut moves = vec![];
+    if crucible.run < max_run {
+        moves.push((crucible.dir, crucible.run + 1));
+    }
+    // Nothing has moved yet at the start, so any direction is fine there
+    if crucible.run == 0 || crucible.run >= min_run {
+        moves.push((crucible.dir.turn_left(), 1));
+        moves.push((crucible.dir.turn_right(), 1));
+    }
+    moves
+        .into_iter()
+        .filter_map(|(dir, run)| {
+            let pos = crucible.pos + dir.into();
+            let heat_loss = *grid.get_ref(pos)? as usize;
+            Some((Crucible { pos, dir, run }, heat_loss))
+        })
+        .collect()
+}
+
+/// Least heat lost getting from the top-left to the bottom-right block, or
+/// None if the crucible can't get there
+fn min_heat_loss(grid: &Grid2D<u8>, min_run: u8, max_run: u8) -> Option<usize> {
+    let goal = Point::new(grid.width - 1, grid.height - 1);
+    // Every block loses at least this much heat, which may be 0
+    let min_block = grid.cells.iter().min().copied().unwrap_or_default() as usize;
+    let start = Crucible {
+        pos: Point::new(0, 0),
+        dir: Direction::Right,
+        run: 0,
+    };
+    a_star(
+        start,
+        |crucible| next_moves(grid, crucible, min_run, max_run),
+        // A run of 0 is only possible before moving, when start is the goal
+        |crucible| crucible.pos == goal && (crucible.run == 0 || crucible.run >= min_run),
+        |crucible| crucible.pos.manhattan_distance(&goal) as usize * min_block,
+    )
+    .map(|path| path.cost)
+}
+
+fn solve(grid: &Grid2D<u8>, min_run: u8, max_run: u8) -> Result<usize> {
+    min_heat_loss(grid, min_run, max_run).ok_or_else(|| AocError::Unsolvable {
+        day: Day17::DAY,
+        message: "the crucible can't reach the factory".to_string(),
+    })
+}
+
+impl Solution for Day17 {
+    const DAY: u32 = 17;
+    const EXAMPLES: &'static [&'static str] = &[SAMPLE, UNFORTUNATE_SAMPLE];
+    type Input<'a> = Grid2D<u8>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_input(input).map_err(|e| e.for_day(Self::DAY))
+    }
+
+    fn part_one(grid: &Self::Input<'_>) -> Result<Self::PartOne> {
+        solve(grid, 1, 3)
+    }
+
+    fn part_two(grid: &Self::Input<'_>) -> Result<Self::PartTwo> {
+        solve(grid, 4, 10)
+    }
+}
+
+const SAMPLE: &str = "\
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533";
+
+/// Ultra crucibles have to keep going straight until they can stop at the end
+const UNFORTUNATE_SAMPLE: &str = "\
+111111111111
+999999999991
+999999999991
+999999999991
+999999999991";
+
+crate::aoc_examples! {
+    solution: Day17,
+    examples: {
+        sample { input: SAMPLE, part1: 102, part2: 94 },
+        unfortunate_sample { input: UNFORTUNATE_SAMPLE, part2: 71 },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let grid = parse_input(SAMPLE).unwrap();
+        assert_eq!(grid.width, 13);
+        assert_eq!(grid.get_ref((1, 1).into()).unwrap(), &2);
+    }
+
+    #[test]
+    fn test_unreachable_factory() {
+        // Ultra crucibles can't make 4 blocks in a row on a 3x3 grid
+        let grid = parse_input("123\n456\n789").unwrap();
+        assert_eq!(min_heat_loss(&grid, 1, 3), Some(20));
+        assert!(matches!(
+            Day17::part_two(&grid),
+            Err(AocError::Unsolvable { day: 17, .. })
+        ));
+        // Starting on the factory loses nothing
+        let grid = parse_input("7").unwrap();
+        assert_eq!(min_heat_loss(&grid, 4, 10), Some(0));
+    }
+
+    #[test]
+    fn test_zero_heat_path() {
+        // Down the zeros on the left loses 1, but a heuristic that assumes
+        // every block loses at least 1 overestimates on that route and lets
+        // the search settle for 2 through the top right instead
+        let grid = parse_input("019\n011\n100\n010").unwrap();
+        assert_eq!(min_heat_loss(&grid, 1, 3), Some(1));
+    }
+}
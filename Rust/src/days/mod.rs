@@ -0,0 +1,45 @@
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+
+use crate::solution::Registered;
+
+/// Every solved (or partially solved) day, in calendar order
+pub const SOLUTIONS: &[Registered] = &[
+    Registered::new::<day01::Day01>(),
+    Registered::new::<day02::Day02>(),
+    Registered::new::<day03::Day03>(),
+    Registered::new::<day04::Day04>(),
+    Registered::new::<day05::Day05>(),
+    Registered::new::<day06::Day06>(),
+    Registered::new::<day07::Day07>(),
+    Registered::new::<day08::Day08>(),
+    Registered::new::<day09::Day09>(),
+    Registered::new::<day10::Day10>(),
+    Registered::new::<day11::Day11>(),
+    Registered::new::<day12::Day12>(),
+    Registered::new::<day13::Day13>(),
+    Registered::new::<day14::Day14>(),
+    Registered::new::<day15::Day15>(),
+    Registered::new::<day16::Day16>(),
+    Registered::new::<day17::Day17>(),
+];
+
+/// Look up the registered solution for a day
+pub fn get(day: u32) -> Option<&'static Registered> {
+    SOLUTIONS.iter().find(|s| s.day == day)
+}
@@ -0,0 +1,239 @@
+use std::{fmt::Display, ops::RangeInclusive, path::PathBuf, time::Duration};
+
+use crate::point::Point;
+
+/// Everything that can go wrong loading, parsing or solving a puzzle
+#[derive(Debug)]
+pub enum AocError {
+    /// `AOC_HOME` is neither set in the environment nor in `.env`, and there's
+    /// no `puzzle_inputs` directory in the repository to fall back on
+    MissingAocHome,
+    /// Puzzle input file doesn't exist at the resolved path
+    MissingInput {
+        day: u32,
+        path: PathBuf,
+    },
+    /// Solution has no embedded example with this (1-based) index
+    MissingExample {
+        day: u32,
+        index: usize,
+    },
+    /// Input couldn't be parsed. `line` and `column` are 1-based.
+    Parse {
+        day: u32,
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// Grid cell couldn't be parsed, or the grid isn't rectangular. `point`
+    /// is the 0-based coordinate of the offending cell.
+    Grid {
+        point: Point,
+        message: String,
+    },
+    /// Coordinate, row or column outside of a `width` x `height` grid
+    OutOfBounds {
+        point: Point,
+        width: i64,
+        height: i64,
+    },
+    /// Part hasn't been solved yet
+    Unsolved {
+        day: u32,
+        part: u32,
+    },
+    /// Input parsed fine but has no answer, e.g. no path or no reflection
+    Unsolvable {
+        day: u32,
+        message: String,
+    },
+    /// Day outside of 1..=25
+    InvalidDay {
+        day: u32,
+    },
+    /// `AOC_SESSION` is needed to download inputs but isn't set
+    MissingSession,
+    /// Puzzle hasn't unlocked yet
+    Locked {
+        day: u32,
+        remaining: Duration,
+    },
+    /// Downloading an input failed
+    Http {
+        url: String,
+        message: String,
+    },
+    /// `answers.toml` couldn't be read or written
+    AnswerFile {
+        path: PathBuf,
+        message: String,
+    },
+    /// A benchmark baseline couldn't be read or written
+    Baseline {
+        path: PathBuf,
+        message: String,
+    },
+    /// `aoc new` won't overwrite an existing file
+    AlreadyExists {
+        path: PathBuf,
+    },
+    /// `aoc new` was pointed at a directory without `src/days/mod.rs`
+    NotACrate {
+        path: PathBuf,
+    },
+    /// No solution is registered for the requested day(s)
+    NoSolution {
+        days: RangeInclusive<u32>,
+    },
+    Io(std::io::Error),
+}
+
+impl AocError {
+    /// Build a `Parse` error from a nom failure on a single line of input.
+    /// `line_no` is 1-based; the column is derived from how much of `line`
+    /// nom consumed before failing.
+    pub fn from_nom(
+        day: u32,
+        line_no: usize,
+        line: &str,
+        err: nom::Err<nom::error::Error<&str>>,
+    ) -> Self {
+        let (rest, message) = match err {
+            nom::Err::Error(e) | nom::Err::Failure(e) => match e.code {
+                nom::error::ErrorKind::Eof => (e.input, "unexpected trailing input".to_string()),
+                code => (
+                    e.input,
+                    format!("unexpected input ({})", code.description()),
+                ),
+            },
+            nom::Err::Incomplete(_) => ("", "unexpected end of line".to_string()),
+        };
+        AocError::Parse {
+            day,
+            line: line_no,
+            column: line.len() - rest.len() + 1,
+            message,
+        }
+    }
+}
+
+impl AocError {
+    /// Attach the day to a `Grid` error, turning it into a `Parse` error with
+    /// 1-based line and column. Other errors are returned unchanged.
+    pub fn for_day(self, day: u32) -> Self {
+        match self {
+            AocError::Grid { point, message } => AocError::Parse {
+                day,
+                line: point.y as usize + 1,
+                column: point.x as usize + 1,
+                message,
+            },
+            other => other,
+        }
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocError::MissingAocHome => write!(
+                f,
+                "AOC_HOME is not set (environment or .env) and no puzzle_inputs directory was found"
+            ),
+            AocError::MissingInput { day, path } => {
+                write!(f, "day {day:02}: input file not found: {}", path.display())
+            }
+            AocError::MissingExample { day, index } => {
+                write!(f, "day {day:02} has no example #{index}")
+            }
+            AocError::Parse {
+                day,
+                line,
+                column,
+                message,
+            } => write!(f, "day {day:02}: parse error at {line}:{column}: {message}"),
+            AocError::Grid { point, message } => write!(f, "parse error at {point}: {message}"),
+            AocError::OutOfBounds {
+                point,
+                width,
+                height,
+            } => write!(f, "{point} is outside of the {width}x{height} grid"),
+            AocError::Unsolved { day, part } => {
+                write!(f, "day {day:02} part {part} is not solved yet")
+            }
+            AocError::Unsolvable { day, message } => write!(f, "day {day:02}: {message}"),
+            AocError::InvalidDay { day } => write!(f, "invalid day {day}, expected 1..=25"),
+            AocError::MissingSession => {
+                write!(f, "AOC_SESSION is not set (environment or .env)")
+            }
+            AocError::Locked { day, remaining } => {
+                let secs = remaining.as_secs();
+                write!(
+                    f,
+                    "day {day:02} unlocks in {}h{:02}m{:02}s",
+                    secs / 3600,
+                    secs % 3600 / 60,
+                    secs % 60
+                )
+            }
+            AocError::Http { url, message } => write!(f, "GET {url} failed: {message}"),
+            AocError::AnswerFile { path, message } | AocError::Baseline { path, message } => {
+                write!(f, "{}: {message}", path.display())
+            }
+            AocError::AlreadyExists { path } => {
+                write!(f, "{} already exists, not overwriting it", path.display())
+            }
+            AocError::NotACrate { path } => write!(
+                f,
+                "{} has no src/days/mod.rs; run from the crate directory or pass --dir",
+                path.display()
+            ),
+            AocError::NoSolution { days } if days.start() == days.end() => {
+                write!(f, "no solution registered for day {}", days.start())
+            }
+            AocError::NoSolution { days } => write!(
+                f,
+                "no solutions registered for days {}..={}",
+                days.start(),
+                days.end()
+            ),
+            AocError::Io(e) => write!(f, "I/O error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AocError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for AocError {
+    fn from(value: std::io::Error) -> Self {
+        AocError::Io(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use nom::{bytes::complete::tag, IResult};
+
+    use super::*;
+
+    fn game_tag(s: &str) -> IResult<&str, &str> {
+        tag("Game ")(s)
+    }
+
+    #[test]
+    fn test_from_nom_column() {
+        let line = "Gme 1: 3 blue";
+        let err = game_tag(line).unwrap_err();
+        let AocError::Parse { line, column, .. } = AocError::from_nom(2, 4, line, err) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((line, column), (4, 1));
+    }
+}
@@ -0,0 +1,139 @@
+use std::fmt::Display;
+
+use crate::solution::Solution;
+
+/// Generate tests that run a solution against the examples from the puzzle
+/// text. Answers are compared by their `Display` output, like `aoc verify`.
+///
+/// A single example produces `examples::{parse, part1, part2}`:
+///
+/// ```text
+/// aoc_examples! {
+///     solution: Day03,
+///     input: TEST_INPUT,
+///     part1: 4361,
+///     part2: 467835,
+/// }
+/// ```
+///
+/// Several named examples produce one module each, e.g.
+/// `examples::large::part2`:
+///
+/// ```text
+/// aoc_examples! {
+///     solution: Day10,
+///     examples: {
+///         small { input: SAMPLE, part1: 8 },
+///         large { input: LARGE_SAMPLE, part2: 8 },
+///     }
+/// }
+/// ```
+///
+/// A part without an answer still gets a test, marked `#[ignore]`; running it
+/// with `--ignored` shows what the part returns. Attributes on an answer are
+/// applied to its test, e.g. `#[ignore = "not solved yet"] part2: 400`.
+#[macro_export]
+macro_rules! aoc_examples {
+    (
+        solution: $solution:ty,
+        input: $input:expr
+        $(, $($answers:tt)*)?
+    ) => {
+        #[cfg(test)]
+        mod examples {
+            use super::*;
+
+            $crate::aoc_examples!(@answers $solution, $input, [], [], $($($answers)*)?);
+        }
+    };
+    (
+        solution: $solution:ty,
+        examples: {
+            $($name:ident { input: $input:expr $(, $($answers:tt)*)? }),+ $(,)?
+        } $(,)?
+    ) => {
+        #[cfg(test)]
+        mod examples {
+            use super::*;
+
+            $(
+                mod $name {
+                    use super::*;
+
+                    $crate::aoc_examples!(@answers $solution, $input, [], [], $($($answers)*)?);
+                }
+            )+
+        }
+    };
+
+    // Sort the answers into part one and part two, in any order
+    (@answers $solution:ty, $input:expr, [$($one:tt)*], [$($two:tt)*], $(,)?) => {
+        #[test]
+        fn parse() {
+            $crate::examples::parse_example::<$solution>($input);
+        }
+
+        $crate::aoc_examples!(@part $solution, $input, 1, part1, [$($one)*]);
+        $crate::aoc_examples!(@part $solution, $input, 2, part2, [$($two)*]);
+    };
+    (
+        @answers $solution:ty, $input:expr, [], [$($two:tt)*],
+        $(#[$attr:meta])* part1: $expected:expr $(, $($rest:tt)*)?
+    ) => {
+        $crate::aoc_examples!(
+            @answers $solution, $input, [[$(#[$attr])*] $expected], [$($two)*], $($($rest)*)?
+        );
+    };
+    (
+        @answers $solution:ty, $input:expr, [$($one:tt)*], [],
+        $(#[$attr:meta])* part2: $expected:expr $(, $($rest:tt)*)?
+    ) => {
+        $crate::aoc_examples!(
+            @answers $solution, $input, [$($one)*], [[$(#[$attr])*] $expected], $($($rest)*)?
+        );
+    };
+
+    (@part $solution:ty, $input:expr, $part:literal, $name:ident, []) => {
+        #[test]
+        #[ignore = "no expected answer"]
+        fn $name() {
+            $crate::examples::check_example::<$solution>($input, $part, None::<&str>);
+        }
+    };
+    (@part $solution:ty, $input:expr, $part:literal, $name:ident, [[$(#[$attr:meta])*] $expected:expr]) => {
+        #[test]
+        $(#[$attr])*
+        fn $name() {
+            $crate::examples::check_example::<$solution>($input, $part, Some($expected));
+        }
+    };
+}
+
+/// Parse an example, panicking with the error if it fails
+pub fn parse_example<S: Solution>(input: &str) -> S::Input<'_> {
+    S::parse(input).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Run `part` (1 or 2) of `S` on an example and compare it with `expected`.
+/// Without an expected answer the test fails, reporting the actual answer.
+pub fn check_example<S: Solution>(input: &str, part: u32, expected: Option<impl Display>) {
+    let parsed = parse_example::<S>(input);
+    let actual = match part {
+        1 => S::part_one(&parsed).map(|answer| answer.to_string()),
+        2 => S::part_two(&parsed).map(|answer| answer.to_string()),
+        _ => panic!("there are only two parts, got part {part}"),
+    }
+    .unwrap_or_else(|e| panic!("{e}"));
+    match expected {
+        Some(expected) => assert_eq!(
+            actual,
+            expected.to_string(),
+            "day {:02} part {part}",
+            S::DAY
+        ),
+        None => panic!(
+            "no expected answer for day {:02} part {part}, got {actual}",
+            S::DAY
+        ),
+    }
+}
@@ -0,0 +1,203 @@
+use std::{
+    fs,
+    path::PathBuf,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{input, AocError, Result};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u32 = 2023;
+
+/// Downloads puzzle inputs from an AoC-compatible server into the local
+/// `puzzle_inputs` cache. A cached input is never downloaded again.
+#[derive(Debug, Clone)]
+pub struct Fetcher {
+    base_url: String,
+    session: String,
+    year: u32,
+    cache_dir: PathBuf,
+}
+
+/// Where a fetched input came from
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    /// Already present in the cache, nothing was downloaded
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+impl Fetcher {
+    pub fn new(
+        base_url: impl Into<String>,
+        session: impl Into<String>,
+        cache_dir: impl Into<PathBuf>,
+    ) -> Self {
+        Fetcher {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session: session.into(),
+            year: YEAR,
+            cache_dir: cache_dir.into(),
+        }
+    }
+
+    /// Configure from the environment (or `.env`): `AOC_SESSION` is required,
+    /// `AOC_BASE_URL` defaults to adventofcode.com, and inputs are cached in the
+    /// same `puzzle_inputs` directory the runner reads from.
+    pub fn from_env() -> Result<Self> {
+        let session = dotenv::var("AOC_SESSION").map_err(|_| AocError::MissingSession)?;
+        let base_url = dotenv::var("AOC_BASE_URL").unwrap_or(DEFAULT_BASE_URL.to_string());
+        Ok(Fetcher::new(base_url, session, input::puzzle_input_dir()?))
+    }
+
+    /// Path of the cached input for `day`
+    pub fn cache_path(&self, day: u32) -> PathBuf {
+        self.cache_dir.join(format!("day{:02}.txt", day))
+    }
+
+    /// Fetch `day`'s input unless it's already cached
+    pub fn fetch(&self, day: u32) -> Result<Fetched> {
+        self.fetch_at(day, SystemTime::now())
+    }
+
+    /// Same as `fetch`, with the current time passed in so unlock checks can be tested
+    pub fn fetch_at(&self, day: u32, now: SystemTime) -> Result<Fetched> {
+        if !(1..=25).contains(&day) {
+            return Err(AocError::InvalidDay { day });
+        }
+        let path = self.cache_path(day);
+        if path.exists() {
+            return Ok(Fetched::Cached(path));
+        }
+        if let Ok(remaining) = unlock_time(self.year, day).duration_since(now) {
+            if !remaining.is_zero() {
+                return Err(AocError::Locked { day, remaining });
+            }
+        }
+
+        let url = format!("{}/{}/day/{}/input", self.base_url, self.year, day);
+        let http_error = |e: ureq::Error| AocError::Http {
+            url: url.clone(),
+            message: e.to_string(),
+        };
+        let input = ureq::get(&url)
+            .header("Cookie", &format!("session={}", self.session))
+            .header("User-Agent", "rust_aoc2023 input fetcher")
+            .call()
+            .map_err(http_error)?
+            .body_mut()
+            .read_to_string()
+            .map_err(http_error)?;
+
+        fs::create_dir_all(&self.cache_dir)?;
+        fs::write(&path, input)?;
+        Ok(Fetched::Downloaded(path))
+    }
+}
+
+/// Puzzles unlock at midnight EST (05:00 UTC) on each day of December
+pub fn unlock_time(year: u32, day: u32) -> SystemTime {
+    // Days since the epoch for `year`-12-01 (civil-from-days, March-based year)
+    let y = year as u64;
+    let days_before_dec_1 = y * 365 + y / 4 - y / 100 + y / 400 + 275 - 719_469;
+    let secs = (days_before_dec_1 + day as u64) * 86_400 + 5 * 3_600;
+    UNIX_EPOCH + Duration::from_secs(secs)
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    use super::*;
+
+    /// Stand-in for the AoC server: answers a single request with `status` and
+    /// `body`, and hands back the raw request it received
+    fn serve_once(status: &'static str, body: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = vec![];
+            let mut buf = [0u8; 1024];
+            while !request.ends_with(b"\r\n\r\n") {
+                let n = stream.read(&mut buf).unwrap();
+                request.extend_from_slice(&buf[..n]);
+            }
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            String::from_utf8(request).unwrap()
+        });
+        (base_url, handle)
+    }
+
+    fn temp_cache_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "rust_aoc2023_fetch_{}_{}",
+            std::process::id(),
+            name
+        ));
+        _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        let secs = |t: SystemTime| t.duration_since(UNIX_EPOCH).unwrap().as_secs();
+        // 2023-12-01T05:00:00Z and 2023-12-25T05:00:00Z
+        assert_eq!(secs(unlock_time(2023, 1)), 1_701_406_800);
+        assert_eq!(secs(unlock_time(2023, 25)), 1_703_480_400);
+        // 2024 is a leap year
+        assert_eq!(secs(unlock_time(2024, 1)), 1_733_029_200);
+    }
+
+    #[test]
+    fn test_fetch_downloads_once() {
+        let cache_dir = temp_cache_dir("download");
+        let (base_url, server) = serve_once("200 OK", "1 2 3\n");
+        let fetcher = Fetcher::new(base_url, "s3cret", &cache_dir);
+
+        let path = fetcher.cache_path(5);
+        assert_eq!(fetcher.fetch(5).unwrap(), Fetched::Downloaded(path.clone()));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2 3\n");
+
+        let request = server.join().unwrap().to_lowercase();
+        assert!(request.starts_with("get /2023/day/5/input http/1.1"));
+        assert!(request.contains("cookie: session=s3cret"));
+
+        // The stand-in server is gone, so this only succeeds if nothing is requested
+        assert_eq!(fetcher.fetch(5).unwrap(), Fetched::Cached(path));
+        fs::remove_dir_all(cache_dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_refuses_locked_day() {
+        let cache_dir = temp_cache_dir("locked");
+        let fetcher = Fetcher::new("http://127.0.0.1:9", "s3cret", &cache_dir);
+        let now = unlock_time(YEAR, 12) - Duration::from_secs(60);
+        match fetcher.fetch_at(12, now) {
+            Err(AocError::Locked { day, remaining }) => {
+                assert_eq!((day, remaining), (12, Duration::from_secs(60)))
+            }
+            other => panic!("expected Locked, got {other:?}"),
+        }
+        assert!(!cache_dir.exists());
+    }
+
+    #[test]
+    fn test_fetch_http_error_is_not_cached() {
+        let cache_dir = temp_cache_dir("http_error");
+        let (base_url, server) = serve_once("404 Not Found", "not found");
+        let fetcher = Fetcher::new(base_url, "s3cret", &cache_dir);
+        assert!(matches!(fetcher.fetch(18), Err(AocError::Http { .. })));
+        server.join().unwrap();
+        assert!(!fetcher.cache_path(18).exists());
+    }
+}
@@ -0,0 +1,214 @@
+use std::str::FromStr;
+
+use crate::{
+    gcd,
+    point::{Direction, Point},
+};
+
+/// Which way a polygon's vertices go around it, as drawn with y pointing down
+/// like the puzzle grids
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    Clockwise,
+    CounterClockwise,
+    /// No area, e.g. all vertices on one line
+    Degenerate,
+}
+
+/// Where a point is relative to a polygon
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+/// Consecutive pairs of vertices, including the closing edge
+fn edges(polygon: &[Point]) -> impl Iterator<Item = (Point, Point)> + '_ {
+    polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .map(|(a, b)| (*a, *b))
+}
+
+/// Twice the signed area (shoelace formula), which is always an integer for
+/// lattice polygons. Positive for clockwise polygons with y pointing down.
+pub fn doubled_signed_area(polygon: &[Point]) -> i64 {
+    edges(polygon).map(|(a, b)| a.x * b.y - a.y * b.x).sum()
+}
+
+pub fn area(polygon: &[Point]) -> f64 {
+    doubled_signed_area(polygon).abs() as f64 / 2.0
+}
+
+pub fn orientation(polygon: &[Point]) -> Orientation {
+    match doubled_signed_area(polygon).signum() {
+        1 => Orientation::Clockwise,
+        -1 => Orientation::CounterClockwise,
+        _ => Orientation::Degenerate,
+    }
+}
+
+/// Number of lattice points on the boundary. An edge from `a` to `b` passes
+/// through `gcd(|dx|, |dy|)` of them, not counting `a`.
+pub fn boundary_points(polygon: &[Point]) -> i64 {
+    edges(polygon)
+        .map(|(a, b)| gcd((b.x - a.x).unsigned_abs(), (b.y - a.y).unsigned_abs()) as i64)
+        .sum()
+}
+
+/// Number of lattice points strictly inside, by Pick's theorem:
+/// `A = I + B/2 - 1`, so `I = (2A - B + 2) / 2`
+pub fn interior_points(polygon: &[Point]) -> i64 {
+    (doubled_signed_area(polygon).abs() - boundary_points(polygon) + 2) / 2
+}
+
+/// Whether `p` is on an edge of `a` to `b`
+fn on_edge(p: Point, a: Point, b: Point) -> bool {
+    let cross = (b.x - a.x) * (p.y - a.y) - (b.y - a.y) * (p.x - a.x);
+    cross == 0
+        && (a.x.min(b.x)..=a.x.max(b.x)).contains(&p.x)
+        && (a.y.min(b.y)..=a.y.max(b.y)).contains(&p.y)
+}
+
+/// Point-in-polygon test by counting the edges a ray from `p` towards +x
+/// crosses
+pub fn locate(polygon: &[Point], p: Point) -> Location {
+    let mut inside = false;
+    for (a, b) in edges(polygon) {
+        if on_edge(p, a, b) {
+            return Location::Boundary;
+        }
+        // Half-open in y so a ray through a vertex counts it once
+        if (a.y > p.y) != (b.y > p.y) {
+            // x where the edge crosses the ray's line, compared without division
+            let lhs = (p.x - a.x) * (b.y - a.y);
+            let rhs = (b.x - a.x) * (p.y - a.y);
+            if (b.y > a.y && lhs < rhs) || (b.y < a.y && lhs > rhs) {
+                inside = !inside;
+            }
+        }
+    }
+    match inside {
+        true => Location::Inside,
+        false => Location::Outside,
+    }
+}
+
+/// One run-length instruction such as `R 6`: move `distance` towards `direction`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    pub direction: Direction,
+    pub distance: i64,
+}
+
+impl FromStr for Step {
+    type Err = String;
+
+    /// Parse `R 6`, ignoring anything after the distance (like day 18's colors)
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let mut parts = s.split_whitespace();
+        let direction = match parts.next() {
+            Some("U") => Direction::Up,
+            Some("D") => Direction::Down,
+            Some("L") => Direction::Left,
+            Some("R") => Direction::Right,
+            other => return Err(format!("invalid direction: {other:?}")),
+        };
+        let distance = parts
+            .next()
+            .and_then(|d| d.parse().ok())
+            .ok_or(format!("invalid distance in {s:?}"))?;
+        Ok(Step {
+            direction,
+            distance,
+        })
+    }
+}
+
+/// Vertices visited by following `steps` from `start`. If the steps lead back
+/// to `start` it isn't repeated at the end.
+pub fn trace(start: Point, steps: &[Step]) -> Vec<Point> {
+    let mut polygon = vec![start];
+    let mut pos = start;
+    for step in steps {
+        let unit = Point::from(step.direction);
+        pos += Point::new(unit.x * step.distance, unit.y * step.distance);
+        polygon.push(pos);
+    }
+    if polygon.len() > 1 && polygon.last() == Some(&start) {
+        polygon.pop();
+    }
+    polygon
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn polygon(points: &[(i64, i64)]) -> Vec<Point> {
+        points.iter().map(|p| Point::from(*p)).collect()
+    }
+
+    #[test]
+    fn test_area_and_orientation() {
+        // 4x3 rectangle, clockwise on screen
+        let rect = polygon(&[(0, 0), (4, 0), (4, 3), (0, 3)]);
+        assert_eq!(doubled_signed_area(&rect), 24);
+        assert_eq!(area(&rect), 12.0);
+        assert_eq!(orientation(&rect), Orientation::Clockwise);
+        let reversed: Vec<Point> = rect.iter().rev().copied().collect();
+        assert_eq!(doubled_signed_area(&reversed), -24);
+        assert_eq!(orientation(&reversed), Orientation::CounterClockwise);
+        let line = polygon(&[(0, 0), (2, 2), (4, 4)]);
+        assert_eq!(orientation(&line), Orientation::Degenerate);
+    }
+
+    #[test]
+    fn test_lattice_points() {
+        let rect = polygon(&[(0, 0), (4, 0), (4, 3), (0, 3)]);
+        assert_eq!(boundary_points(&rect), 14);
+        assert_eq!(interior_points(&rect), 6);
+        // Diagonal edges: (0,0)-(4,2) passes through (2,1)
+        let triangle = polygon(&[(0, 0), (4, 2), (0, 4)]);
+        assert_eq!(boundary_points(&triangle), 2 + 2 + 4);
+        assert_eq!(area(&triangle), 8.0);
+        assert_eq!(interior_points(&triangle), 5);
+    }
+
+    #[test]
+    fn test_locate() {
+        // L-shape
+        let shape = polygon(&[(0, 0), (2, 0), (2, 2), (4, 2), (4, 4), (0, 4)]);
+        assert_eq!(locate(&shape, Point::new(1, 1)), Location::Inside);
+        assert_eq!(locate(&shape, Point::new(3, 3)), Location::Inside);
+        assert_eq!(locate(&shape, Point::new(3, 1)), Location::Outside);
+        assert_eq!(locate(&shape, Point::new(2, 1)), Location::Boundary);
+        assert_eq!(locate(&shape, Point::new(4, 4)), Location::Boundary);
+        // Ray passes exactly through the vertex at (2, 2)
+        assert_eq!(locate(&shape, Point::new(1, 2)), Location::Inside);
+        assert_eq!(locate(&shape, Point::new(-1, 2)), Location::Outside);
+        assert_eq!(locate(&shape, Point::new(5, 4)), Location::Outside);
+    }
+
+    #[test]
+    fn test_trace_steps() {
+        let steps: Vec<Step> = ["R 6 (#70c710)", "D 5", "L 6", "U 5"]
+            .iter()
+            .map(|s| s.parse().unwrap())
+            .collect();
+        assert_eq!(
+            steps[0],
+            Step {
+                direction: Direction::Right,
+                distance: 6
+            }
+        );
+        let lagoon = trace(Point::new(0, 0), &steps);
+        assert_eq!(lagoon, polygon(&[(0, 0), (6, 0), (6, 5), (0, 5)]));
+        // Dug out: the trench plus everything inside it
+        assert_eq!(boundary_points(&lagoon) + interior_points(&lagoon), 42);
+        assert!("X 6".parse::<Step>().is_err());
+        assert!("R six".parse::<Step>().is_err());
+    }
+}
@@ -1,10 +1,14 @@
-use std::fmt::Display;
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::Display,
+    str::FromStr,
+};
 
-use crate::{point::Point, Result};
+use crate::{point::Point, AocError, Result};
 
 /// Generic 2D grid represented by a flat array with methods to convert & find
 /// indexes into the array via (x, y) coordinates
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub struct Grid2D<T> {
     pub width: i64,
     pub height: i64,
@@ -15,9 +19,55 @@ impl<T> Grid2D<T>
 where
     T: PartialEq + Clone + Copy,
 {
+    /// Build a grid from lines of text, turning each character into a cell with
+    /// `parse_cell`. Rows must all be as wide as the first one; line endings
+    /// may be `\n` or `\r\n` and trailing newlines are ignored.
+    pub fn parse_with(
+        input: &str,
+        mut parse_cell: impl FnMut(Point, char) -> Result<T>,
+    ) -> Result<Self> {
+        let input = input.trim_end_matches(['\n', '\r']);
+        let width = input.lines().next().map_or(0, |line| line.chars().count()) as i64;
+        if width == 0 {
+            return Err(AocError::Grid {
+                point: Point::default(),
+                message: "empty grid".to_string(),
+            });
+        }
+        let mut cells = Vec::with_capacity(input.len());
+        let mut height = 0;
+        for (y, line) in input.lines().enumerate() {
+            let y = y as i64;
+            let mut row_width = 0;
+            for (x, c) in line.chars().enumerate() {
+                let point = Point::new(x as i64, y);
+                if point.x >= width {
+                    return Err(AocError::Grid {
+                        point,
+                        message: format!("row is wider than the first row ({width})"),
+                    });
+                }
+                cells.push(parse_cell(point, c)?);
+                row_width += 1;
+            }
+            if row_width < width {
+                return Err(AocError::Grid {
+                    point: Point::new(row_width, y),
+                    message: format!("row is {row_width} wide, expected {width}"),
+                });
+            }
+            height += 1;
+        }
+        Ok(Grid2D {
+            width,
+            height,
+            cells,
+        })
+    }
+
     /// Take index of usize and return a Point value (grid in bounds)
     pub fn idx_to_point(&self, idx: usize) -> Option<Point> {
-        if idx > self.width as usize * self.height as usize {
+        if idx >= self.cells.len() {
             return None;
         }
         let y = idx as i64 / self.width;
@@ -25,9 +75,17 @@ where
         Some(Point::new(x, y))
     }
 
-    /// Find index in self.cells vec for _in_bounds_(!) point
-    pub fn pt_to_idx(&self, p: Point) -> usize {
-        (p.y * self.width + p.x) as usize
+    /// Find index in self.cells vec for a point, or None if it's out of bounds
+    pub fn pt_to_idx(&self, p: Point) -> Option<usize> {
+        self.in_bounds(p).then(|| (p.y * self.width + p.x) as usize)
+    }
+
+    fn out_of_bounds(&self, point: Point) -> AocError {
+        AocError::OutOfBounds {
+            point,
+            width: self.width,
+            height: self.height,
+        }
     }
 
     /// Check if provided (x, y) coordinate is in the bounds of the `Grid`
@@ -37,18 +95,13 @@ where
 
     /// Return a reference to a GridCell
     pub fn get_ref(&self, p: Point) -> Option<&T> {
-        if !self.in_bounds(p) {
-            return None;
-        }
-        Some(&self.cells[self.pt_to_idx(p)])
+        self.cells.get(self.pt_to_idx(p)?)
     }
 
     /// Return a mutable reference to a GridCell
     pub fn get_mut_ref(&mut self, p: Point) -> Option<&mut T> {
-        if !self.in_bounds(p) {
-            return None;
-        }
-        Some(&mut self.cells[(p.y * self.width + p.x) as usize])
+        let idx = self.pt_to_idx(p)?;
+        self.cells.get_mut(idx)
     }
 
     /// Return set of all in-bounds coordinates surrounding a point
@@ -83,17 +136,13 @@ where
         None
     }
 
-    /// Return vec of `Point`s where element is found. None if element is not found.
-    pub fn find_all(&self, element: T) -> Option<Vec<Point>> {
+    /// Return vec of `Point`s where element is found, empty if there are none
+    pub fn find_all(&self, element: T) -> Vec<Point> {
         self.cells
             .iter()
             .enumerate()
-            .filter_map(|(idx, item)| {
-                if item == &element {
-                    return Some(self.idx_to_point(idx));
-                }
-                None
-            })
+            .filter(|(_, item)| **item == element)
+            .map(|(idx, _)| Point::from_index(idx, self.width))
             .collect()
     }
 
@@ -106,6 +155,71 @@ where
         Some(&self.cells[start_idx..start_idx + self.width as usize])
     }
 
+    /// Iterate over the rows, top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // Not `chunks_exact`, so a zero-width grid still has `height` rows
+        let width = self.width as usize;
+        (0..self.height as usize).map(move |y| &self.cells[y * width..(y + 1) * width])
+    }
+
+    /// Iterate over the columns, left to right, each an iterator over its cells
+    /// from top to bottom
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let width = self.width as usize;
+        // A zero-height grid has no cells, but still `width` (empty) columns
+        (0..width).map(move |x| {
+            self.cells
+                .get(x..)
+                .unwrap_or_default()
+                .iter()
+                .step_by(width)
+        })
+    }
+
+    /// Iterate over every cell with its coordinate, row by row
+    pub fn iter_points(&self) -> impl Iterator<Item = (Point, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(idx, item)| (Point::from_index(idx, width), item))
+    }
+
+    /// Like `iter_points`, with mutable cells
+    pub fn iter_mut_points(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        let width = self.width;
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(idx, item)| (Point::from_index(idx, width), item))
+    }
+
+    /// Borrowed `width` x `height` section of the grid with its top left
+    /// corner at `origin`, or None if it doesn't fit or is empty
+    pub fn subgrid(&self, origin: Point, width: i64, height: i64) -> Option<SubGrid<'_, T>> {
+        let fits = width > 0
+            && height > 0
+            && origin.x >= 0
+            && origin.y >= 0
+            && origin.x + width <= self.width
+            && origin.y + height <= self.height;
+        fits.then_some(SubGrid {
+            grid: self,
+            origin,
+            width,
+            height,
+        })
+    }
+
+    /// Every `width` x `height` section of the grid, row by row. Empty if
+    /// either size is zero.
+    pub fn windows(&self, width: i64, height: i64) -> impl Iterator<Item = SubGrid<'_, T>> {
+        let (xs, ys) = (self.width - width + 1, self.height - height + 1);
+        (0..ys.max(0))
+            .flat_map(move |y| (0..xs.max(0)).map(move |x| Point::new(x, y)))
+            .filter_map(move |origin| self.subgrid(origin, width, height))
+    }
+
     /// Return a slice representing a column in the grid
     pub fn get_column(&self, x: i64) -> Option<Vec<T>> {
         if !self.in_bounds(Point::new(x, 0)) {
@@ -118,29 +232,519 @@ where
         Some(column)
     }
 
-    /// Add column to grid.cells
+    /// Add column to grid.cells before column `x`; `x == width` appends one
     pub fn insert_column(&mut self, x: i64, element: T) -> Result<()> {
-        self.width += 1;
-        for y in 0..self.height {
-            let new_idx = self.pt_to_idx((x, y).into());
-            self.cells.insert(new_idx, element)
+        self.insert_columns(x, 1, element)
+    }
+
+    /// Add row to grid.cells before row `y`; `y == height` appends one
+    pub fn insert_row(&mut self, y: i64, element: T) -> Result<()> {
+        self.insert_rows(y, 1, element)
+    }
+
+    /// Add `count` columns of `fill` before column `at`, rebuilding the cells
+    /// once
+    pub fn insert_columns(&mut self, at: i64, count: usize, fill: T) -> Result<()> {
+        if !(0..=self.width).contains(&at) {
+            return Err(self.out_of_bounds(Point::new(at, 0)));
         }
+        let at = at as usize;
+        let width = self.width as usize + count;
+        let mut cells = Vec::with_capacity(width * self.height as usize);
+        for row in self.rows() {
+            cells.extend_from_slice(&row[..at]);
+            cells.extend(std::iter::repeat_n(fill, count));
+            cells.extend_from_slice(&row[at..]);
+        }
+        self.cells = cells;
+        self.width = width as i64;
         Ok(())
     }
 
-    /// Add row to grid.cells
-    pub fn insert_row(&mut self, y: i64, element: T) -> Result<()> {
-        let start_idx = y * self.width;
-        for x in start_idx..start_idx + self.width {
-            self.cells.insert(x as usize, element)
+    /// Add `count` rows of `fill` before row `at`
+    pub fn insert_rows(&mut self, at: i64, count: usize, fill: T) -> Result<()> {
+        if !(0..=self.height).contains(&at) {
+            return Err(self.out_of_bounds(Point::new(0, at)));
+        }
+        let start = (at * self.width) as usize;
+        let added = count * self.width as usize;
+        self.cells
+            .splice(start..start, std::iter::repeat_n(fill, added));
+        self.height += count as i64;
+        Ok(())
+    }
+
+    pub fn remove_column(&mut self, x: i64) -> Result<()> {
+        self.remove_columns(x, 1)
+    }
+
+    pub fn remove_row(&mut self, y: i64) -> Result<()> {
+        self.remove_rows(y, 1)
+    }
+
+    /// Remove `count` columns starting at column `at`
+    pub fn remove_columns(&mut self, at: i64, count: usize) -> Result<()> {
+        let end = at + count as i64;
+        if at < 0 || end > self.width {
+            return Err(self.out_of_bounds(Point::new(at.max(end - 1), 0)));
+        }
+        let width = self.width;
+        let mut idx = 0;
+        self.cells.retain(|_| {
+            let x = idx % width;
+            idx += 1;
+            !(at..end).contains(&x)
+        });
+        self.width -= count as i64;
+        Ok(())
+    }
+
+    /// Remove `count` rows starting at row `at`
+    pub fn remove_rows(&mut self, at: i64, count: usize) -> Result<()> {
+        let end = at + count as i64;
+        if at < 0 || end > self.height {
+            return Err(self.out_of_bounds(Point::new(0, at.max(end - 1))));
         }
-        self.height += 1;
+        self.cells
+            .drain((at * self.width) as usize..(end * self.width) as usize);
+        self.height -= count as i64;
         Ok(())
     }
 
+    /// Change the size of the grid, keeping the cells that still fit in place
+    /// and filling new ones with `fill`
+    pub fn resize(&mut self, width: i64, height: i64, fill: T) {
+        let (width, height) = (width.max(0), height.max(0));
+        let keep = self.width.min(width) as usize;
+        let mut cells = Vec::with_capacity((width * height) as usize);
+        for row in self.rows().take(height as usize) {
+            cells.extend_from_slice(&row[..keep]);
+            cells.extend(std::iter::repeat_n(fill, width as usize - keep));
+        }
+        cells.resize((width * height) as usize, fill);
+        self.cells = cells;
+        self.width = width;
+        self.height = height;
+    }
+
+    /// Surround the grid with a `border` cells wide frame of `fill`
+    pub fn pad(&mut self, border: usize, fill: T) {
+        let width = self.width as usize + 2 * border;
+        let height = self.height as usize + 2 * border;
+        let mut cells = Vec::with_capacity(width * height);
+        cells.extend(std::iter::repeat_n(fill, border * width));
+        for row in self.rows() {
+            cells.extend(std::iter::repeat_n(fill, border));
+            cells.extend_from_slice(row);
+            cells.extend(std::iter::repeat_n(fill, border));
+        }
+        cells.extend(std::iter::repeat_n(fill, border * width));
+        self.cells = cells;
+        self.width = width as i64;
+        self.height = height as i64;
+    }
+
+    /// Set a cell. Panics if `pt` is out of bounds; see `try_set`.
     pub fn set(&mut self, pt: Point, item: T) {
-        let idx = self.pt_to_idx(pt);
-        self.cells[idx] = item;
+        if let Err(e) = self.try_set(pt, item) {
+            panic!("{e}");
+        }
+    }
+
+    /// Set a cell, or fail if `pt` is out of bounds
+    pub fn try_set(&mut self, pt: Point, item: T) -> Result<()> {
+        match self.get_mut_ref(pt) {
+            Some(cell) => {
+                *cell = item;
+                Ok(())
+            }
+            None => Err(self.out_of_bounds(pt)),
+        }
+    }
+
+    /// Cell at `p` as if the grid repeated forever in every direction, or
+    /// None if the grid is empty
+    pub fn get_wrapped(&self, p: Point) -> Option<&T> {
+        self.get_ref(self.wrap_point(p)?)
+    }
+
+    /// Coordinate within the grid that `p` lands on when the grid is tiled,
+    /// or None if the grid is empty
+    pub fn wrap_point(&self, p: Point) -> Option<Point> {
+        if self.width == 0 || self.height == 0 {
+            return None;
+        }
+        Some(Point::new(
+            p.x.rem_euclid(self.width),
+            p.y.rem_euclid(self.height),
+        ))
+    }
+
+    /// View of the grid repeated forever in every direction
+    pub fn tiled(&self) -> TiledGrid<'_, T> {
+        TiledGrid { grid: self }
+    }
+
+    /// Zero-copy view of the grid that can be rotated, transposed and flipped
+    pub fn view(&self) -> GridView<'_, T> {
+        GridView {
+            grid: self,
+            transposed: false,
+            flip_x: false,
+            flip_y: false,
+        }
+    }
+
+    /// New grid rotated 90° clockwise
+    pub fn rotated_cw(&self) -> Grid2D<T> {
+        self.view().rotate_cw().to_grid()
+    }
+
+    /// New grid rotated 90° counter-clockwise
+    pub fn rotated_ccw(&self) -> Grid2D<T> {
+        self.view().rotate_ccw().to_grid()
+    }
+
+    /// New grid with rows and columns swapped
+    pub fn transposed(&self) -> Grid2D<T> {
+        self.view().transpose().to_grid()
+    }
+
+    /// New grid mirrored left to right
+    pub fn flipped_horizontal(&self) -> Grid2D<T> {
+        self.view().flip_horizontal().to_grid()
+    }
+
+    /// New grid mirrored top to bottom
+    pub fn flipped_vertical(&self) -> Grid2D<T> {
+        self.view().flip_vertical().to_grid()
+    }
+
+    /// Rotate 90° clockwise in place
+    pub fn rotate_cw(&mut self) {
+        self.transpose();
+        self.flip_horizontal();
+    }
+
+    /// Rotate 90° counter-clockwise in place
+    pub fn rotate_ccw(&mut self) {
+        self.transpose();
+        self.flip_vertical();
+    }
+
+    /// Swap rows and columns in place. Only square grids avoid a new allocation.
+    pub fn transpose(&mut self) {
+        if self.width != self.height {
+            *self = self.transposed();
+            return;
+        }
+        let size = self.width as usize;
+        for y in 0..size {
+            for x in y + 1..size {
+                self.cells.swap(y * size + x, x * size + y);
+            }
+        }
+    }
+
+    /// Mirror left to right in place
+    pub fn flip_horizontal(&mut self) {
+        self.cells
+            .chunks_exact_mut(self.width.max(1) as usize)
+            .for_each(|row| row.reverse());
+    }
+
+    /// Mirror top to bottom in place
+    pub fn flip_vertical(&mut self) {
+        let width = self.width as usize;
+        let height = self.height as usize;
+        for y in 0..height / 2 {
+            let (top, bottom) = self.cells.split_at_mut((height - 1 - y) * width);
+            top[y * width..(y + 1) * width].swap_with_slice(&mut bottom[..width]);
+        }
+    }
+}
+
+/// Borrowed, possibly rotated/flipped view of a `Grid2D`. Transformations only
+/// change how view coordinates map onto the grid, so they are free to stack.
+#[derive(Debug, Clone, Copy)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid2D<T>,
+    /// View (x, y) reads grid (y, x), applied before the flips
+    transposed: bool,
+    flip_x: bool,
+    flip_y: bool,
+}
+
+impl<'a, T> GridView<'a, T> {
+    pub fn width(&self) -> i64 {
+        match self.transposed {
+            true => self.grid.height,
+            false => self.grid.width,
+        }
+    }
+
+    pub fn height(&self) -> i64 {
+        match self.transposed {
+            true => self.grid.width,
+            false => self.grid.height,
+        }
+    }
+
+    pub fn in_bounds(&self, p: Point) -> bool {
+        (p.x < self.width() && p.x >= 0) && (p.y < self.height() && p.y >= 0)
+    }
+
+    /// Coordinate in the underlying grid for a coordinate in the view
+    pub fn grid_point(&self, p: Point) -> Point {
+        let (x, y) = match self.transposed {
+            true => (p.y, p.x),
+            false => (p.x, p.y),
+        };
+        Point::new(
+            if self.flip_x {
+                self.grid.width - 1 - x
+            } else {
+                x
+            },
+            if self.flip_y {
+                self.grid.height - 1 - y
+            } else {
+                y
+            },
+        )
+    }
+
+    pub fn get_ref(&self, p: Point) -> Option<&'a T> {
+        if !self.in_bounds(p) {
+            return None;
+        }
+        let p = self.grid_point(p);
+        Some(&self.grid.cells[(p.y * self.grid.width + p.x) as usize])
+    }
+
+    /// Iterate over a row of the view
+    pub fn row(&self, y: i64) -> impl Iterator<Item = &'a T> + '_ {
+        (0..self.width()).filter_map(move |x| self.get_ref(Point::new(x, y)))
+    }
+
+    /// Iterate over a column of the view
+    pub fn column(&self, x: i64) -> impl Iterator<Item = &'a T> + '_ {
+        (0..self.height()).filter_map(move |y| self.get_ref(Point::new(x, y)))
+    }
+
+    pub fn transpose(self) -> Self {
+        GridView {
+            transposed: !self.transposed,
+            ..self
+        }
+    }
+
+    pub fn flip_horizontal(self) -> Self {
+        match self.transposed {
+            true => GridView {
+                flip_y: !self.flip_y,
+                ..self
+            },
+            false => GridView {
+                flip_x: !self.flip_x,
+                ..self
+            },
+        }
+    }
+
+    pub fn flip_vertical(self) -> Self {
+        self.transpose().flip_horizontal().transpose()
+    }
+
+    pub fn rotate_cw(self) -> Self {
+        self.transpose().flip_horizontal()
+    }
+
+    pub fn rotate_ccw(self) -> Self {
+        self.transpose().flip_vertical()
+    }
+}
+
+impl<T> GridView<'_, T>
+where
+    T: Copy,
+{
+    /// Copy the view into a new grid
+    pub fn to_grid(&self) -> Grid2D<T> {
+        let cells = (0..self.height())
+            .flat_map(|y| self.row(y).copied().collect::<Vec<T>>())
+            .collect();
+        Grid2D {
+            width: self.width(),
+            height: self.height(),
+            cells,
+        }
+    }
+}
+
+/// Borrowed rectangular section of a `Grid2D`. Coordinates are relative to
+/// `origin`, the section's top left corner in the grid.
+#[derive(Debug, Clone, Copy)]
+pub struct SubGrid<'a, T> {
+    grid: &'a Grid2D<T>,
+    pub origin: Point,
+    pub width: i64,
+    pub height: i64,
+}
+
+impl<'a, T> SubGrid<'a, T>
+where
+    T: PartialEq + Clone + Copy,
+{
+    pub fn in_bounds(&self, p: Point) -> bool {
+        (p.x < self.width && p.x >= 0) && (p.y < self.height && p.y >= 0)
+    }
+
+    /// Coordinate in the underlying grid for a coordinate in the section
+    pub fn grid_point(&self, p: Point) -> Point {
+        self.origin + p
+    }
+
+    pub fn get_ref(&self, p: Point) -> Option<&'a T> {
+        if !self.in_bounds(p) {
+            return None;
+        }
+        self.grid.get_ref(self.grid_point(p))
+    }
+
+    /// Iterate over the rows of the section, top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + '_ {
+        (0..self.height).filter_map(move |y| {
+            let start = self.grid.pt_to_idx(self.grid_point(Point::new(0, y)))?;
+            self.grid.cells.get(start..start + self.width as usize)
+        })
+    }
+
+    /// Iterate over every cell with its coordinate in the section, row by row
+    pub fn iter_points(&self) -> impl Iterator<Item = (Point, &'a T)> + '_ {
+        self.rows().enumerate().flat_map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .map(move |(x, item)| (Point::new(x as i64, y as i64), item))
+        })
+    }
+
+    /// Copy the section into a new grid
+    pub fn to_grid(&self) -> Grid2D<T> {
+        Grid2D {
+            width: self.width,
+            height: self.height,
+            cells: self.rows().flatten().copied().collect(),
+        }
+    }
+}
+
+impl<T> std::fmt::Display for SubGrid<'_, T>
+where
+    T: PartialEq + Clone + Copy + Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// A `Grid2D` repeated infinitely in every direction. Tile (0, 0) is the grid
+/// itself; tile (-1, 0) is the copy directly to its left.
+#[derive(Debug, Clone, Copy)]
+pub struct TiledGrid<'a, T> {
+    grid: &'a Grid2D<T>,
+}
+
+impl<'a, T> TiledGrid<'a, T>
+where
+    T: PartialEq + Clone + Copy,
+{
+    /// Cell at `p`, or None if the grid is empty
+    pub fn get_ref(&self, p: Point) -> Option<&'a T> {
+        self.grid.get_wrapped(p)
+    }
+
+    /// Which copy of the grid `p` lives in, or None if the grid is empty
+    pub fn tile(&self, p: Point) -> Option<Point> {
+        if self.grid.width == 0 || self.grid.height == 0 {
+            return None;
+        }
+        Some(Point::new(
+            p.x.div_euclid(self.grid.width),
+            p.y.div_euclid(self.grid.height),
+        ))
+    }
+
+    /// N, S, E, W neighbors of a point; there is no edge to fall off, so
+    /// there are always four unless the grid is empty
+    pub fn get_neighbor4_cells(&self, p: Point) -> Vec<(Point, &'a T)> {
+        p.get_4_neighbors()
+            .into_iter()
+            .filter_map(|n| Some((n, self.get_ref(n)?)))
+            .collect()
+    }
+
+    /// Fewest steps from `start` to every point reachable in at most
+    /// `max_steps` steps, only moving through cells where `is_open` holds
+    pub fn distances(
+        &self,
+        start: Point,
+        max_steps: usize,
+        is_open: impl Fn(&T) -> bool,
+    ) -> HashMap<Point, usize> {
+        let mut distances = HashMap::from([(start, 0)]);
+        let mut queue = VecDeque::from([(start, 0)]);
+        while let Some((p, steps)) = queue.pop_front() {
+            if steps == max_steps {
+                continue;
+            }
+            for (n, cell) in self.get_neighbor4_cells(p) {
+                if is_open(cell) && !distances.contains_key(&n) {
+                    distances.insert(n, steps + 1);
+                    queue.push_back((n, steps + 1));
+                }
+            }
+        }
+        distances
+    }
+
+    /// Number of cells where a walk of exactly `steps` steps from `start` can
+    /// end. Stepping back and forth wastes two steps, so that's every cell
+    /// within reach whose distance has the same parity as `steps`.
+    pub fn count_reachable(
+        &self,
+        start: Point,
+        steps: usize,
+        is_open: impl Fn(&T) -> bool,
+    ) -> usize {
+        self.distances(start, steps, is_open)
+            .values()
+            .filter(|d| *d % 2 == steps % 2)
+            .count()
+    }
+}
+
+/// Parse a grid of any cell type that can be made from a single character
+impl<T> FromStr for Grid2D<T>
+where
+    T: TryFrom<char> + PartialEq + Clone + Copy,
+    T::Error: Display,
+{
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Grid2D::parse_with(s, |point, c| {
+            T::try_from(c).map_err(|e| AocError::Grid {
+                point,
+                message: e.to_string(),
+            })
+        })
     }
 }
 
@@ -173,6 +777,21 @@ where
     }
 }
 
+impl<T> std::fmt::Display for GridView<'_, T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.height() {
+            for cell in self.row(y) {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -197,6 +816,385 @@ mod test {
         assert!(grid.get_row(3).unwrap().iter().all(|c| *c == '.'));
     }
 
+    const ABC: &str = "\
+abc
+def";
+
+    #[test]
+    fn test_rotate_and_flip() {
+        let grid: Grid2D<char> = ABC.into();
+        assert_eq!(grid.rotated_cw().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotated_ccw().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.transposed().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.flipped_horizontal().to_string(), "cba\nfed\n");
+        assert_eq!(grid.flipped_vertical().to_string(), "def\nabc\n");
+        assert_eq!(
+            grid.rotated_cw().rotated_cw(),
+            grid.view().rotate_cw().rotate_cw().to_grid()
+        );
+        assert_eq!(grid.rotated_cw().rotated_ccw(), grid);
+    }
+
+    #[test]
+    fn test_in_place_matches_returning() {
+        type InPlace = fn(&mut Grid2D<char>);
+        type Returning = fn(&Grid2D<char>) -> Grid2D<char>;
+        let ops: [(InPlace, Returning); 5] = [
+            (Grid2D::rotate_cw, Grid2D::rotated_cw),
+            (Grid2D::rotate_ccw, Grid2D::rotated_ccw),
+            (Grid2D::transpose, Grid2D::transposed),
+            (Grid2D::flip_horizontal, Grid2D::flipped_horizontal),
+            (Grid2D::flip_vertical, Grid2D::flipped_vertical),
+        ];
+        for input in [ABC, "abc\ndef\nghi", TEST_INPUT1] {
+            let grid: Grid2D<char> = input.into();
+            for (in_place, returning) in ops {
+                let mut changed = grid.clone();
+                in_place(&mut changed);
+                assert_eq!(changed, returning(&grid));
+            }
+        }
+    }
+
+    #[test]
+    fn test_view() {
+        let grid: Grid2D<char> = ABC.into();
+        let view = grid.view().rotate_cw();
+        assert_eq!((view.width(), view.height()), (2, 3));
+        assert_eq!(view.get_ref((0, 0).into()), Some(&'d'));
+        assert_eq!(view.grid_point((0, 0).into()), Point::new(0, 1));
+        assert_eq!(view.get_ref((2, 0).into()), None);
+        assert_eq!(view.row(1).collect::<String>(), "eb");
+        assert_eq!(view.column(1).collect::<String>(), "abc");
+        assert_eq!(view.to_string(), "da\neb\nfc\n");
+        // Four rotations and two flips are both the identity
+        let view = view.rotate_cw().rotate_cw().rotate_cw();
+        assert_eq!(view.to_grid(), grid);
+        let view = grid.view().flip_vertical().flip_vertical();
+        assert_eq!(view.to_grid(), grid);
+    }
+
+    /// Tiny xorshift generator so the property tests need no extra crates
+    /// and are reproducible from their seed
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        /// Uniform-ish value in `0..n`
+        fn below(&mut self, n: i64) -> i64 {
+            (self.next() % n as u64) as i64
+        }
+
+        fn grid(&mut self) -> Grid2D<u8> {
+            // Include zero-width and zero-height shapes
+            let (width, height) = (self.below(6), self.below(6));
+            let cells = (0..width * height).map(|_| self.below(4) as u8).collect();
+            Grid2D {
+                width,
+                height,
+                cells,
+            }
+        }
+    }
+
+    fn assert_invariants(grid: &Grid2D<u8>, context: &str) {
+        assert_eq!(
+            grid.width * grid.height,
+            grid.cells.len() as i64,
+            "{context}"
+        );
+        for i in 0..grid.cells.len() {
+            let p = grid.idx_to_point(i).unwrap();
+            assert!(grid.in_bounds(p), "{context}");
+            assert_eq!(grid.pt_to_idx(p), Some(i), "{context}");
+        }
+        assert_eq!(grid.idx_to_point(grid.cells.len()), None, "{context}");
+        for p in [
+            Point::new(-1, 0),
+            Point::new(0, -1),
+            Point::new(grid.width, 0),
+            Point::new(0, grid.height),
+        ] {
+            assert_eq!(grid.pt_to_idx(p), None, "{context}");
+        }
+        assert_eq!(grid.rows().count() as i64, grid.height, "{context}");
+        assert_eq!(grid.columns().count() as i64, grid.width, "{context}");
+        for (x, column) in grid.columns().enumerate() {
+            let expected: Vec<&u8> = (0..grid.height)
+                .filter_map(|y| grid.get_ref(Point::new(x as i64, y)))
+                .collect();
+            assert_eq!(column.collect::<Vec<_>>(), expected, "{context}");
+        }
+    }
+
+    fn transform(grid: &mut Grid2D<u8>, op: i64, rng: &mut Rng) {
+        match op {
+            3 => grid.rotate_cw(),
+            4 => grid.rotate_ccw(),
+            5 => grid.transpose(),
+            6 => grid.flip_horizontal(),
+            7 => grid.flip_vertical(),
+            8 => *grid = grid.rotated_cw(),
+            13 => grid.pad(rng.below(3) as usize, 9),
+            _ => grid.resize(grid.height, grid.width + 1, 9),
+        }
+    }
+
+    #[test]
+    fn prop_invariants_hold_after_mutations() {
+        for seed in 1..=200 {
+            let mut rng = Rng(seed);
+            let mut grid = rng.grid();
+            assert_invariants(&grid, &format!("seed {seed}: initial"));
+            for step in 0..20 {
+                let (x, y) = (rng.below(10) - 2, rng.below(10) - 2);
+                let item = rng.below(4) as u8;
+                let count = rng.below(3) as usize;
+                let op = rng.below(15);
+                let valid = match op {
+                    0 => grid.in_bounds(Point::new(x, y)),
+                    1 | 9 => (0..=grid.height).contains(&y),
+                    2 | 10 => (0..=grid.width).contains(&x),
+                    11 => y >= 0 && y + count as i64 <= grid.height,
+                    12 => x >= 0 && x + count as i64 <= grid.width,
+                    _ => true,
+                };
+                let result = match op {
+                    0 => grid.try_set(Point::new(x, y), item),
+                    1 => grid.insert_row(y, item),
+                    2 => grid.insert_column(x, item),
+                    9 => grid.insert_rows(y, count, item),
+                    10 => grid.insert_columns(x, count, item),
+                    11 => grid.remove_rows(y, count),
+                    12 => grid.remove_columns(x, count),
+                    _ => {
+                        transform(&mut grid, op, &mut rng);
+                        Ok(())
+                    }
+                };
+                let context = format!("seed {seed}, step {step}: op {op} at ({x}, {y})");
+                assert_eq!(result.is_ok(), valid, "{context}");
+                if op == 0 && valid {
+                    assert_eq!(grid.get_ref(Point::new(x, y)), Some(&item), "{context}");
+                }
+                assert_invariants(&grid, &context);
+            }
+        }
+    }
+
+    #[test]
+    fn prop_find_all_matches_cells() {
+        for seed in 1..=100 {
+            let mut rng = Rng(seed);
+            let grid = rng.grid();
+            for item in 0..4 {
+                let found = grid.find_all(item);
+                let expected = grid.cells.iter().filter(|c| **c == item).count();
+                assert_eq!(found.len(), expected, "seed {seed}");
+                assert!(found.iter().all(|p| grid.get_ref(*p) == Some(&item)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_bulk_insert_and_remove() {
+        let mut grid: Grid2D<char> = ABC.into();
+        grid.insert_columns(1, 2, '.').unwrap();
+        grid.insert_rows(1, 1, '-').unwrap();
+        assert_eq!(grid.to_string(), "a..bc\n-----\nd..ef\n");
+        grid.remove_columns(1, 2).unwrap();
+        grid.remove_row(1).unwrap();
+        assert_eq!(grid, ABC.into());
+        assert!(grid.remove_columns(2, 2).is_err());
+        assert!(grid.remove_rows(-1, 1).is_err());
+        grid.remove_column(0).unwrap();
+        assert_eq!(grid.to_string(), "bc\nef\n");
+    }
+
+    #[test]
+    fn test_insert_columns_into_zero_width_grid() {
+        let mut grid: Grid2D<char> = ABC.into();
+        grid.remove_columns(0, 3).unwrap();
+        assert_eq!((grid.width, grid.height, grid.cells.len()), (0, 2, 0));
+        grid.insert_columns(0, 1, 'x').unwrap();
+        assert_eq!((grid.width, grid.height), (1, 2));
+        assert_eq!(grid.to_string(), "x\nx\n");
+        grid.resize(0, 3, '.');
+        grid.pad(1, '#');
+        assert_eq!(grid.to_string(), "##\n##\n##\n##\n##\n");
+    }
+
+    #[test]
+    fn test_columns_of_zero_height_grid() {
+        let mut grid: Grid2D<char> = ABC.into();
+        grid.remove_rows(0, 2).unwrap();
+        assert_eq!((grid.width, grid.height), (3, 0));
+        let columns: Vec<usize> = grid.columns().map(|column| column.count()).collect();
+        assert_eq!(columns, vec![0, 0, 0]);
+    }
+
+    #[test]
+    fn test_resize_and_pad() {
+        let mut grid: Grid2D<char> = ABC.into();
+        grid.pad(1, '#');
+        assert_eq!(grid.to_string(), "#####\n#abc#\n#def#\n#####\n");
+        let mut grid: Grid2D<char> = ABC.into();
+        grid.resize(2, 3, '.');
+        assert_eq!(grid.to_string(), "ab\nde\n..\n");
+        grid.resize(4, 1, '.');
+        assert_eq!(grid.to_string(), "ab..\n");
+    }
+
+    #[test]
+    fn test_checked_access() {
+        let mut grid: Grid2D<char> = ABC.into();
+        assert_eq!(grid.idx_to_point(5), Some(Point::new(2, 1)));
+        assert_eq!(grid.idx_to_point(6), None);
+        assert!(grid.try_set(Point::new(3, 0), 'x').is_err());
+        assert!(grid.try_set(Point::new(-1, 1), 'x').is_err());
+        assert!(grid.insert_row(3, 'x').is_err());
+        assert!(grid.insert_column(-1, 'x').is_err());
+        assert!(grid.find_all('z').is_empty());
+        grid.insert_row(2, 'x').unwrap();
+        grid.insert_column(3, 'y').unwrap();
+        assert_eq!(grid.to_string(), "abcy\ndefy\nxxxy\n");
+    }
+
+    #[test]
+    fn test_iterators() {
+        let mut grid: Grid2D<char> = ABC.into();
+        let rows: Vec<&[char]> = grid.rows().collect();
+        assert_eq!(rows, vec![&['a', 'b', 'c'][..], &['d', 'e', 'f'][..]]);
+        let columns: Vec<String> = grid.columns().map(|c| c.collect()).collect();
+        assert_eq!(columns, vec!["ad", "be", "cf"]);
+        assert_eq!(grid.iter_points().nth(4), Some((Point::new(1, 1), &'e')));
+        for (p, c) in grid.iter_mut_points() {
+            if p.x == p.y {
+                *c = c.to_ascii_uppercase();
+            }
+        }
+        assert_eq!(grid.to_string(), "Abc\ndEf\n");
+    }
+
+    #[test]
+    fn test_subgrid_and_windows() {
+        let grid: Grid2D<char> = "abcd\nefgh\nijkl".into();
+        let sub = grid.subgrid(Point::new(1, 1), 2, 2).unwrap();
+        assert_eq!(sub.to_string(), "fg\njk\n");
+        assert_eq!(sub.get_ref(Point::new(1, 0)), Some(&'g'));
+        assert_eq!(sub.get_ref(Point::new(2, 0)), None);
+        assert_eq!(sub.grid_point(Point::new(1, 1)), Point::new(2, 2));
+        assert_eq!(sub.iter_points().last(), Some((Point::new(1, 1), &'k')));
+        assert!(grid.subgrid(Point::new(3, 0), 2, 1).is_none());
+        assert!(grid.subgrid(Point::new(-1, 0), 1, 1).is_none());
+
+        let windows: Vec<String> = grid.windows(3, 2).map(|w| w.to_string()).collect();
+        assert_eq!(
+            windows,
+            vec!["abc\nefg\n", "bcd\nfgh\n", "efg\nijk\n", "fgh\njkl\n"]
+        );
+        assert_eq!(grid.windows(5, 1).count(), 0);
+        assert_eq!(grid.windows(1, 1).count(), 12);
+        // Zero-sized sections are rejected rather than yielding empty views
+        assert_eq!(grid.windows(0, 1).count(), 0);
+        assert_eq!(grid.windows(2, 0).count(), 0);
+        assert!(grid.subgrid(Point::new(4, 3), 0, 0).is_none());
+    }
+
+    #[test]
+    fn test_parse_with() {
+        let digits = |point: Point, c: char| {
+            c.to_digit(10).ok_or(AocError::Grid {
+                point,
+                message: format!("not a digit: {c:?}"),
+            })
+        };
+        let grid = Grid2D::parse_with("12\r\n34\r\n\r\n", digits).unwrap();
+        assert_eq!((grid.width, grid.height), (2, 2));
+        assert_eq!(grid.cells, vec![1, 2, 3, 4]);
+        let err = Grid2D::parse_with("12\n3x", digits).unwrap_err();
+        assert!(matches!(err, AocError::Grid { point, .. } if point == Point::new(1, 1)));
+        let AocError::Parse { line, column, .. } = err.for_day(3) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((line, column), (2, 2));
+    }
+
+    #[test]
+    fn test_parse_ragged_rows() {
+        let short = "abc\nab\nabc".parse::<Grid2D<char>>().unwrap_err();
+        assert!(matches!(short, AocError::Grid { point, .. } if point == Point::new(2, 1)));
+        let long = "abc\nabcd".parse::<Grid2D<char>>().unwrap_err();
+        assert!(matches!(long, AocError::Grid { point, .. } if point == Point::new(3, 1)));
+        assert!("".parse::<Grid2D<char>>().is_err());
+        let grid: Grid2D<char> = "abc\ndef\n".parse().unwrap();
+        assert_eq!(grid, ABC.into());
+    }
+
+    const GARDEN: &str = "\
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........";
+
+    #[test]
+    fn test_tiled() {
+        let grid: Grid2D<char> = ABC.into();
+        let tiled = grid.tiled();
+        assert_eq!(
+            grid.get_wrapped(Point::new(-1, -1)),
+            grid.get_ref(Point::new(2, 1))
+        );
+        assert_eq!(grid.wrap_point(Point::new(7, -5)), Some(Point::new(1, 1)));
+        assert_eq!(tiled.tile(Point::new(2, 1)), Some(Point::new(0, 0)));
+        assert_eq!(tiled.tile(Point::new(-1, 2)), Some(Point::new(-1, 1)));
+        assert_eq!(tiled.tile(Point::new(-4, -3)), Some(Point::new(-2, -2)));
+        let neighbors = tiled.get_neighbor4_cells(Point::new(0, 0));
+        assert_eq!(neighbors.len(), 4);
+        assert!(neighbors.contains(&(Point::new(-1, 0), grid.get_ref(Point::new(2, 0)).unwrap())));
+    }
+
+    #[test]
+    fn test_tiled_empty_grid() {
+        let grid: Grid2D<char> = Grid2D {
+            width: 0,
+            height: 0,
+            cells: vec![],
+        };
+        let tiled = grid.tiled();
+        assert_eq!(grid.wrap_point(Point::new(3, 4)), None);
+        assert_eq!(grid.get_wrapped(Point::new(3, 4)), None);
+        assert_eq!(tiled.get_ref(Point::new(0, 0)), None);
+        assert_eq!(tiled.tile(Point::new(3, 4)), None);
+        assert!(tiled.get_neighbor4_cells(Point::new(0, 0)).is_empty());
+        // Only the start is reachable when there's nowhere to go
+        assert_eq!(tiled.count_reachable(Point::new(0, 0), 4, |_| true), 1);
+    }
+
+    #[test]
+    fn test_count_reachable() {
+        let grid: Grid2D<char> = GARDEN.into();
+        let start = grid.find_item_coord(&'S').unwrap();
+        let tiled = grid.tiled();
+        let open = |c: &char| *c != '#';
+        assert_eq!(tiled.count_reachable(start, 6, open), 16);
+        assert_eq!(tiled.count_reachable(start, 10, open), 50);
+        assert_eq!(tiled.count_reachable(start, 50, open), 1594);
+    }
+
     #[test]
     fn test_euclid_distance() {
         let pt1 = Point::new(-2, -1);
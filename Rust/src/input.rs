@@ -0,0 +1,122 @@
+use std::{
+    fs::File,
+    io::{ErrorKind, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::{AocError, Result};
+
+/// Where a run reads its puzzle input from
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum InputSource {
+    /// `puzzle_inputs/dayNN.txt`
+    #[default]
+    Default,
+    /// `puzzle_inputs/dayNN.<name>.txt`, e.g. a teammate's input
+    Alternate(String),
+    /// Explicit path to an input file
+    File(PathBuf),
+    /// Read everything from standard input
+    Stdin,
+    /// Example embedded in the solution (`Solution::EXAMPLES`), 1-based
+    Example(usize),
+}
+
+impl InputSource {
+    /// Load the input for `day`. `examples` are the day's embedded examples,
+    /// only used by `InputSource::Example`.
+    pub fn load(&self, day: u32, examples: &[&str]) -> Result<String> {
+        match self {
+            InputSource::Default => read_input_file(day, &puzzle_input_path(day)?),
+            InputSource::Alternate(name) => {
+                read_input_file(day, &puzzle_input_dir()?.join(file_name(day, Some(name))))
+            }
+            InputSource::File(path) => read_input_file(day, path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                std::io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            InputSource::Example(index) => index
+                .checked_sub(1)
+                .and_then(|i| examples.get(i))
+                .map(|example| example.to_string())
+                .ok_or(AocError::MissingExample { day, index: *index }),
+        }
+    }
+}
+
+/// `dayNN.txt`, or `dayNN.<alt>.txt` for an alternate input
+fn file_name(day: u32, alt: Option<&str>) -> String {
+    match alt {
+        Some(alt) => format!("day{:02}.{}.txt", day, alt),
+        None => format!("day{:02}.txt", day),
+    }
+}
+
+/// Resolve the `puzzle_inputs` directory: `$AOC_HOME/puzzle_inputs` if
+/// `AOC_HOME` is set (environment or `.env`), otherwise the one next to this
+/// crate in the repository (the same place the Python `utils.py` looks).
+pub fn puzzle_input_dir() -> Result<PathBuf> {
+    if let Ok(aoc_home) = dotenv::var("AOC_HOME") {
+        return Ok(PathBuf::from(aoc_home).join("puzzle_inputs"));
+    }
+    let repo_dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .map(|p| p.join("puzzle_inputs"));
+    match repo_dir {
+        Some(dir) if dir.is_dir() => Ok(dir),
+        _ => Err(AocError::MissingAocHome),
+    }
+}
+
+/// Default location of a day's input, `<puzzle_input_dir>/dayNN.txt`
+pub fn puzzle_input_path(day: u32) -> Result<PathBuf> {
+    Ok(puzzle_input_dir()?.join(file_name(day, None)))
+}
+
+/// Open an input file, reporting the resolved path if it's missing
+pub(crate) fn open_input_file(day: u32, path: &Path) -> Result<File> {
+    File::open(path).map_err(|e| match e.kind() {
+        ErrorKind::NotFound => AocError::MissingInput {
+            day,
+            path: path.to_path_buf(),
+        },
+        _ => AocError::Io(e),
+    })
+}
+
+fn read_input_file(day: u32, path: &Path) -> Result<String> {
+    Ok(std::io::read_to_string(open_input_file(day, path)?)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_file_name() {
+        assert_eq!(file_name(5, None), "day05.txt");
+        assert_eq!(file_name(5, Some("alt")), "day05.alt.txt");
+    }
+
+    #[test]
+    fn test_load_example() {
+        let examples = ["first", "second"];
+        let source = InputSource::Example(2);
+        assert_eq!(source.load(1, &examples).unwrap(), "second");
+        assert!(matches!(
+            InputSource::Example(3).load(1, &examples),
+            Err(AocError::MissingExample { day: 1, index: 3 })
+        ));
+    }
+
+    #[test]
+    fn test_missing_file_reports_path() {
+        let path = PathBuf::from("does/not/exist.txt");
+        match InputSource::File(path.clone()).load(7, &[]) {
+            Err(AocError::MissingInput { day: 7, path: p }) => assert_eq!(p, path),
+            other => panic!("expected MissingInput, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,303 @@
+use std::ops::{Add, Range, Sub};
+
+/// Sort `ranges` and join any that overlap or touch, dropping empty ones
+pub fn merge<T: Copy + Ord>(ranges: impl IntoIterator<Item = Range<T>>) -> Vec<Range<T>> {
+    let mut ranges: Vec<Range<T>> = ranges.into_iter().filter(|r| r.start < r.end).collect();
+    ranges.sort_unstable_by_key(|r| r.start);
+    let mut merged: Vec<Range<T>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+/// Set of values stored as sorted, disjoint, non-touching ranges
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet { ranges: vec![] }
+    }
+}
+
+impl<T: Copy + Ord> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The normalized ranges, in increasing order
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Smallest value in the set
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|r| r.start)
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        // First range starting after `value`; only the one before can hold it
+        let i = self.ranges.partition_point(|r| r.start <= *value);
+        i > 0 && self.ranges[i - 1].contains(value)
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        let ranges = std::mem::take(&mut self.ranges);
+        self.ranges = merge(ranges.into_iter().chain([range]));
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        IntervalSet {
+            ranges: merge(self.ranges.iter().chain(&other.ranges).cloned()),
+        }
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (&self.ranges[i], &other.ranges[j]);
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+            if start < end {
+                ranges.push(start..end);
+            }
+            // Whichever ends first can't overlap anything further along
+            match a.end < b.end {
+                true => i += 1,
+                false => j += 1,
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// Values in `self` that aren't in `other`
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let mut j = 0;
+        for range in &self.ranges {
+            let mut start = range.start;
+            // Skip the ranges of `other` entirely before this one
+            while j < other.ranges.len() && other.ranges[j].end <= start {
+                j += 1;
+            }
+            let mut k = j;
+            while k < other.ranges.len() && other.ranges[k].start < range.end {
+                let cut = &other.ranges[k];
+                if start < cut.start {
+                    ranges.push(start..cut.start);
+                }
+                start = start.max(cut.end);
+                k += 1;
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+        IntervalSet { ranges }
+    }
+}
+
+impl<T: Copy + Ord + Sub<Output = T> + Add<Output = T> + Default> IntervalSet<T> {
+    /// Number of values in the set
+    pub fn total_length(&self) -> T {
+        self.ranges
+            .iter()
+            .fold(T::default(), |total, r| total + (r.end - r.start))
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        IntervalSet {
+            ranges: merge(iter),
+        }
+    }
+}
+
+/// Piecewise map made of segments that each shift a source range to start at
+/// a destination; values outside every segment map to themselves
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeMap<T> {
+    /// `(source, destination start)`, sorted by source and not overlapping
+    segments: Vec<(Range<T>, T)>,
+}
+
+impl<T> Default for RangeMap<T> {
+    fn default() -> Self {
+        RangeMap { segments: vec![] }
+    }
+}
+
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T>> RangeMap<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Map of the given `(source, destination)` segments, or None if any of
+    /// them overlap
+    pub fn from_segments(segments: impl IntoIterator<Item = (Range<T>, T)>) -> Option<Self> {
+        let mut map = RangeMap::new();
+        for (source, destination) in segments {
+            if !map.insert(source, destination) {
+                return None;
+            }
+        }
+        Some(map)
+    }
+
+    /// Map `source` onto the range of the same length starting at
+    /// `destination`. Returns false, leaving the map unchanged, if `source`
+    /// overlaps a segment that's already mapped.
+    pub fn insert(&mut self, source: Range<T>, destination: T) -> bool {
+        let i = self
+            .segments
+            .partition_point(|(s, _)| s.start < source.start);
+        let after_previous = i == 0 || self.segments[i - 1].0.end <= source.start;
+        let before_next = i == self.segments.len() || source.end <= self.segments[i].0.start;
+        if !(after_previous && before_next) {
+            return false;
+        }
+        self.segments.insert(i, (source, destination));
+        true
+    }
+
+    pub fn get(&self, value: T) -> T {
+        let i = self.segments.partition_point(|(s, _)| s.start <= value);
+        match i.checked_sub(1).map(|i| &self.segments[i]) {
+            Some((source, destination)) if source.contains(&value) => {
+                *destination + (value - source.start)
+            }
+            _ => value,
+        }
+    }
+
+    /// Image of `range`, split into one piece per segment (or gap between
+    /// segments) it passes through
+    pub fn map_range(&self, range: Range<T>) -> Vec<Range<T>> {
+        let mut pieces = vec![];
+        let mut start = range.start;
+        let first = self.segments.partition_point(|(s, _)| s.end <= start);
+        for (source, destination) in &self.segments[first..] {
+            if start >= range.end || source.start >= range.end {
+                break;
+            }
+            // Unmapped gap before this segment
+            if start < source.start {
+                pieces.push(start..source.start);
+                start = source.start;
+            }
+            let end = source.end.min(range.end);
+            let shift = |v: T| *destination + (v - source.start);
+            pieces.push(shift(start)..shift(end));
+            start = end;
+        }
+        if start < range.end {
+            pieces.push(start..range.end);
+        }
+        pieces
+    }
+
+    /// Image of every range in `set`
+    pub fn map_set(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        set.ranges()
+            .iter()
+            .flat_map(|r| self.map_range(r.clone()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::single_range_in_vec_init)]
+mod test {
+    use super::*;
+
+    fn set(ranges: &[Range<i32>]) -> IntervalSet<i32> {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn test_merge() {
+        assert_eq!(merge([5..8, 1..3, 3..4, 7..10, 12..12]), vec![1..4, 5..10]);
+        let mut s = set(&[1..3]);
+        s.insert(10..12);
+        s.insert(2..10);
+        assert_eq!(s.ranges(), &[1..12]);
+        assert_eq!(s.total_length(), 11);
+        assert!(IntervalSet::<i32>::new().is_empty());
+    }
+
+    #[test]
+    fn test_contains() {
+        let s = set(&[1..3, 5..8]);
+        assert!(!s.contains(&0));
+        assert!(s.contains(&1));
+        assert!(s.contains(&2));
+        assert!(!s.contains(&3));
+        assert!(s.contains(&7));
+        assert!(!s.contains(&8));
+        assert_eq!(s.min(), Some(1));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[0..5, 10..15]);
+        let b = set(&[3..12, 14..20]);
+        assert_eq!(a.union(&b), set(&[0..20]));
+        assert_eq!(a.intersection(&b).ranges(), &[3..5, 10..12, 14..15]);
+        assert_eq!(a.difference(&b).ranges(), &[0..3, 12..14]);
+        assert_eq!(b.difference(&a).ranges(), &[5..10, 15..20]);
+        assert_eq!(a.difference(&a), IntervalSet::new());
+        // Cutting several holes out of one range
+        let holes = set(&[1..2, 4..5, 9..30]);
+        assert_eq!(
+            set(&[0..10]).difference(&holes).ranges(),
+            &[0..1, 2..4, 5..9]
+        );
+    }
+
+    #[test]
+    fn test_range_map() {
+        // 50..98 -> 52..100, 98..100 -> 50..52
+        let map = RangeMap::<u64>::from_segments([(98..100, 50), (50..98, 52)]).unwrap();
+        assert_eq!(map.get(10), 10);
+        assert_eq!(map.get(50), 52);
+        assert_eq!(map.get(79), 81);
+        assert_eq!(map.get(99), 51);
+        assert_eq!(map.get(100), 100);
+        assert_eq!(
+            map.map_range(40..105),
+            vec![40..50, 52..100, 50..52, 100..105]
+        );
+        assert_eq!(map.map_range(60..70), vec![62..72]);
+        assert_eq!(map.map_range(0..10), vec![0..10]);
+        let image = map.map_set(&[40..105].into_iter().collect());
+        assert_eq!(image.ranges(), &[40..105]);
+        assert_eq!(image.total_length(), 65);
+    }
+
+    #[test]
+    fn test_range_map_overlap() {
+        let mut map = RangeMap::<u64>::from_segments([(10..20, 100)]).unwrap();
+        assert!(!map.insert(15..25, 0));
+        assert!(!map.insert(5..11, 0));
+        assert!(!map.insert(10..20, 0));
+        assert!(!map.insert(12..14, 0));
+        // Touching segments don't overlap
+        assert!(map.insert(20..25, 200));
+        assert!(map.insert(5..10, 50));
+        assert_eq!(map.get(22), 202);
+        assert_eq!(map.get(15), 105);
+        assert_eq!(RangeMap::<u64>::from_segments([(0..5, 1), (4..8, 2)]), None);
+    }
+}
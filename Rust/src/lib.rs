@@ -1,27 +1,41 @@
+pub mod answers;
+pub mod bench;
+pub mod compress;
+pub mod cycle;
+pub mod days;
+pub mod error;
+pub mod examples;
+pub mod fetch;
+pub mod geometry;
 pub mod grid;
+pub mod input;
+pub mod interval;
 pub mod point;
+pub mod region;
+pub mod scaffold;
+pub mod search;
+pub mod solution;
+pub mod sparse_grid;
 use std::{
-    fs::{read_to_string, File},
+    fs::File,
     io::{BufRead, BufReader, Lines},
 };
 
-pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+pub use error::AocError;
+use input::InputSource;
+
+pub type Result<T> = std::result::Result<T, AocError>;
 
 /// Shared function to get puzzle input as lines
 pub fn get_puzzle_input_lines(day: u32) -> Result<Lines<BufReader<File>>> {
-    let aoc_home = dotenv::var("AOC_HOME")?;
-    let filename = format!("{}/puzzle_inputs/day{:02}.txt", aoc_home, day);
-    let file = File::open(filename).expect("input file not found");
-    let buf = BufReader::new(file);
+    let path = input::puzzle_input_path(day)?;
+    let buf = BufReader::new(input::open_input_file(day, &path)?);
     Ok(buf.lines())
 }
 
 /// Read puzzle_input to `String`.
 pub fn get_puzzle_input_string(day: u32) -> Result<String> {
-    let aoc_home = dotenv::var("AOC_HOME")?;
-    let filename = format!("{}/puzzle_inputs/day{:02}.txt", aoc_home, day);
-    let data = read_to_string(filename)?;
-    Ok(data)
+    InputSource::Default.load(day, &[])
 }
 
 /// Greatest common divisor function
@@ -1,3 +1,489 @@
-pub fn main() {
-    println!("Hello, Advent of Code 2023!");
+use std::{ops::RangeInclusive, path::PathBuf, process::ExitCode, str::FromStr, time::Duration};
+
+use clap::{error::ErrorKind, Args, CommandFactory, Parser, Subcommand};
+use rust_aoc2023::{
+    answers::{self, AnswerBook, Verdict},
+    bench::{percent_delta, Baseline, CountingAllocator, Phase, Stats, Timings},
+    days,
+    fetch::{Fetched, Fetcher},
+    input::InputSource,
+    scaffold,
+    solution::Registered,
+    AocError, Result,
+};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Advent of Code 2023 solution runner
+#[derive(Debug, Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Run the registered solution(s) against the puzzle input
+    Run {
+        /// A single day (`5`) or a range of days (`3..11`, `3..=11`)
+        #[arg(required_unless_present = "all", conflicts_with = "all")]
+        days: Option<DayRange>,
+        /// Run every registered day
+        #[arg(long)]
+        all: bool,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Time parsing and both parts of the solution(s) separately
+    Bench {
+        /// A single day (`5`) or a range of days (`3..11`, `3..=11`)
+        #[arg(required_unless_present = "all", conflicts_with = "all")]
+        days: Option<DayRange>,
+        /// Benchmark every registered day
+        #[arg(long)]
+        all: bool,
+        #[command(flatten)]
+        bench: BenchArgs,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Download puzzle input(s) into `puzzle_inputs` (needs `AOC_SESSION`)
+    Fetch {
+        /// A single day (`5`) or a range of days (`3..11`, `3..=11`)
+        #[arg(required_unless_present = "all", conflicts_with = "all")]
+        days: Option<DayRange>,
+        /// Fetch every day's input that isn't cached yet
+        #[arg(long)]
+        all: bool,
+    },
+    /// Run solution(s) against the real input and record the answers
+    Record {
+        /// A single day (`5`) or a range of days (`3..11`, `3..=11`)
+        #[arg(required_unless_present = "all", conflicts_with = "all")]
+        days: Option<DayRange>,
+        /// Record every registered day
+        #[arg(long)]
+        all: bool,
+        /// Overwrite answers that are already recorded but differ
+        #[arg(long)]
+        force: bool,
+        /// Answer file (defaults to `puzzle_inputs/answers.toml`)
+        #[arg(long, value_name = "PATH")]
+        answers: Option<PathBuf>,
+    },
+    /// Generate `src/days/dayNN.rs` and `src/bin/dayNN.rs` for a new day
+    New {
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+        /// Crate directory to write into (defaults to the current directory)
+        #[arg(long, value_name = "PATH")]
+        dir: Option<PathBuf>,
+    },
+    /// Check solution(s) against the recorded answers (every day by default)
+    Verify {
+        /// A single day (`5`) or a range of days (`3..11`, `3..=11`)
+        days: Option<DayRange>,
+        /// Answer file (defaults to `puzzle_inputs/answers.toml`)
+        #[arg(long, value_name = "PATH")]
+        answers: Option<PathBuf>,
+    },
+}
+
+/// Alternatives to the default `puzzle_inputs/dayNN.txt`
+#[derive(Debug, Args)]
+#[group(multiple = false)]
+struct InputArgs {
+    /// Read the input from a file (single day only)
+    #[arg(long, value_name = "PATH")]
+    input: Option<PathBuf>,
+    /// Read the input from stdin (single day only)
+    #[arg(long)]
+    stdin: bool,
+    /// Use the alternate input `puzzle_inputs/dayNN.<NAME>.txt`
+    #[arg(long, value_name = "NAME")]
+    alt: Option<String>,
+    /// Use the solution's embedded example (the first one by default)
+    #[arg(long, value_name = "N", num_args = 0..=1, default_missing_value = "1")]
+    example: Option<usize>,
+}
+
+impl InputArgs {
+    fn source(&self) -> InputSource {
+        if let Some(path) = &self.input {
+            InputSource::File(path.clone())
+        } else if self.stdin {
+            InputSource::Stdin
+        } else if let Some(name) = &self.alt {
+            InputSource::Alternate(name.clone())
+        } else if let Some(index) = self.example {
+            InputSource::Example(index)
+        } else {
+            InputSource::Default
+        }
+    }
+}
+
+#[derive(Debug, Args)]
+struct BenchArgs {
+    /// Run each day N times and report min/median/mean
+    #[arg(long, value_name = "N", default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    repeat: u32,
+    /// Also report the number of allocations in each phase
+    #[arg(long)]
+    allocs: bool,
+    /// Save the median times as a JSON baseline
+    #[arg(long, value_name = "PATH")]
+    save: Option<PathBuf>,
+    /// Compare the median times against a baseline saved with `--save`
+    #[arg(long, value_name = "PATH")]
+    compare: Option<PathBuf>,
+    /// Slowdown (in percent) vs. the baseline that counts as a regression
+    #[arg(long, value_name = "PERCENT", default_value_t = 10.0)]
+    threshold: f64,
+}
+
+/// Inclusive range of days, parsed from `N`, `A..B` or `A..=B`
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DayRange(RangeInclusive<u32>);
+
+impl FromStr for DayRange {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let parse_day = |d: &str| {
+            d.trim()
+                .parse::<u32>()
+                .map_err(|_| format!("invalid day: {d:?}"))
+        };
+        let range = if let Some((start, end)) = s.split_once("..=") {
+            parse_day(start)?..=parse_day(end)?
+        } else if let Some((start, end)) = s.split_once("..") {
+            let end = parse_day(end)?;
+            if end == 0 {
+                return Err(format!("empty range: {s:?}"));
+            }
+            parse_day(start)?..=end - 1
+        } else {
+            let day = parse_day(s)?;
+            day..=day
+        };
+        if range.is_empty() {
+            return Err(format!("empty range: {s:?}"));
+        }
+        Ok(DayRange(range))
+    }
+}
+
+/// Registered solutions for the requested days, or every day if `days` is `None`
+fn select(days: Option<DayRange>) -> Result<Vec<&'static Registered>> {
+    let range = days.map_or(1..=25, |DayRange(range)| range);
+    let selected: Vec<&Registered> = days::SOLUTIONS
+        .iter()
+        .filter(|s| range.contains(&s.day))
+        .collect();
+    if selected.is_empty() {
+        return Err(AocError::NoSolution { days: range });
+    }
+    Ok(selected)
+}
+
+/// Run each selected day, reporting errors per day instead of stopping at the
+/// first one. Returns the number of days that failed.
+fn run_days(selected: &[&Registered], source: &InputSource) -> usize {
+    let mut failed = 0;
+    for (i, registered) in selected.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        if let Err(e) = registered.run(source) {
+            eprintln!("error: {e}");
+            failed += 1;
+        }
+    }
+    failed
+}
+
+/// `--input` and `--stdin` provide a single day's input
+fn check_single_day(selected: &[&Registered], source: &InputSource) {
+    if selected.len() > 1 && matches!(source, InputSource::File(_) | InputSource::Stdin) {
+        Cli::command()
+            .error(
+                ErrorKind::ArgumentConflict,
+                "--input and --stdin can only be used with a single day",
+            )
+            .exit();
+    }
+}
+
+fn run(days: Option<DayRange>, input: InputArgs) -> usize {
+    let source = input.source();
+    let selected = match select(days) {
+        Ok(selected) => selected,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return 1;
+        }
+    };
+    check_single_day(&selected, &source);
+    run_days(&selected, &source)
+}
+
+/// Solve each selected day `args.repeat` times and print a table of timings per
+/// phase. Returns the number of days that failed plus the number of phases
+/// that regressed against the `--compare` baseline.
+fn bench(days: Option<DayRange>, input: InputArgs, args: BenchArgs) -> Result<usize> {
+    let source = input.source();
+    let selected = select(days)?;
+    check_single_day(&selected, &source);
+    let baseline = args.compare.as_deref().map(Baseline::load).transpose()?;
+    let mut saved = Baseline::default();
+    let mut problems = 0;
+
+    print!(
+        "{:<5} {:<6} {:>10} {:>10} {:>10}",
+        "Day", "Phase", "Min", "Median", "Mean"
+    );
+    if args.allocs {
+        print!(" {:>10}", "Allocs");
+    }
+    if baseline.is_some() {
+        print!(" {:>10}", "Δ median");
+    }
+    println!();
+
+    for registered in selected {
+        let day = registered.day;
+        let runs = match registered.load_input(&source).and_then(|input| {
+            (0..args.repeat)
+                .map(|_| (registered.solve)(&input).map(|answers| answers.timings))
+                .collect::<Result<Vec<Timings>>>()
+        }) {
+            Ok(runs) => runs,
+            Err(e) => {
+                eprintln!("error: {e}");
+                problems += 1;
+                continue;
+            }
+        };
+        for phase in Phase::ALL {
+            let walls: Vec<Duration> = runs.iter().map(|t| t.get(phase).wall).collect();
+            let Some(stats) = Stats::from_samples(&walls) else {
+                continue;
+            };
+            saved.set(day, phase, stats.median);
+            print!(
+                "{:<5} {:<6} {:>10} {:>10} {:>10}",
+                format!("{day:02}"),
+                phase,
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.mean),
+            );
+            if args.allocs {
+                let allocs = runs.iter().map(|t| t.get(phase).allocations).min();
+                print!(" {:>10}", allocs.unwrap_or_default());
+            }
+            if let Some(baseline) = &baseline {
+                match baseline.get(day, phase) {
+                    Some(before) => {
+                        let delta = percent_delta(before, stats.median);
+                        print!(" {:>+9.1}%", delta);
+                        if delta > args.threshold {
+                            print!(" regression");
+                            problems += 1;
+                        }
+                    }
+                    None => print!(" {:>10}", "-"),
+                }
+            }
+            println!();
+        }
+    }
+
+    if let Some(path) = &args.save {
+        saved.save(path)?;
+        println!("\nbaseline saved to {}", path.display());
+    }
+    Ok(problems)
+}
+
+/// Download each requested day's input, skipping ones that are already cached.
+/// Returns the number of days that failed.
+fn fetch(days: Option<DayRange>) -> usize {
+    let fetcher = match Fetcher::from_env() {
+        Ok(fetcher) => fetcher,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return 1;
+        }
+    };
+    let mut failed = 0;
+    for day in days.map_or(1..=25, |DayRange(range)| range) {
+        match fetcher.fetch(day) {
+            Ok(Fetched::Cached(path)) => println!("Day {day:02}: cached at {}", path.display()),
+            Ok(Fetched::Downloaded(path)) => {
+                println!("Day {day:02}: downloaded to {}", path.display())
+            }
+            Err(e) => {
+                eprintln!("error: {e}");
+                failed += 1;
+            }
+        }
+    }
+    failed
+}
+
+/// Load the answer book from `path` (or the default location)
+fn load_answers(path: Option<PathBuf>) -> Result<(PathBuf, AnswerBook)> {
+    let path = match path {
+        Some(path) => path,
+        None => answers::default_path()?,
+    };
+    let book = AnswerBook::load(&path)?;
+    Ok((path, book))
+}
+
+/// Record each selected day's answers. Answers that differ from the ones on
+/// record are only replaced with `force`. Returns the number of problems.
+fn record(days: Option<DayRange>, force: bool, path: Option<PathBuf>) -> Result<usize> {
+    let selected = select(days)?;
+    let (path, mut book) = load_answers(path)?;
+    let mut problems = 0;
+    for registered in selected {
+        let day = registered.day;
+        let answers = match registered.answers(&InputSource::Default) {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("error: {e}");
+                problems += 1;
+                continue;
+            }
+        };
+        for (part, result) in [(1, answers.part_one), (2, answers.part_two)] {
+            let answer = match result {
+                Ok(answer) => answer,
+                Err(e) => {
+                    println!("Day {day:02} part {part}: skipped ({e})");
+                    continue;
+                }
+            };
+            match book.get(day, part) {
+                Some(recorded) if recorded == answer => {
+                    println!("Day {day:02} part {part}: unchanged {answer}")
+                }
+                Some(recorded) if !force => {
+                    println!(
+                        "Day {day:02} part {part}: kept {recorded}, got {answer} (use --force to overwrite)"
+                    );
+                    problems += 1;
+                }
+                Some(recorded) => {
+                    println!("Day {day:02} part {part}: updated {recorded} -> {answer}");
+                    book.set(day, part, answer);
+                }
+                None => {
+                    println!("Day {day:02} part {part}: recorded {answer}");
+                    book.set(day, part, answer);
+                }
+            }
+        }
+    }
+    book.save(&path)?;
+    Ok(problems)
+}
+
+/// Check each selected day against the recorded answers and print a
+/// PASS/FAIL/MISSING report. Returns the number of failures.
+fn verify(days: Option<DayRange>, path: Option<PathBuf>) -> Result<usize> {
+    let selected = select(days)?;
+    let (_, book) = load_answers(path)?;
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for registered in selected {
+        let day = registered.day;
+        let answers = match registered.answers(&InputSource::Default) {
+            Ok(answers) => answers,
+            Err(e) => {
+                println!("Day {day:02}: FAIL (error: {e})");
+                failed += 1;
+                continue;
+            }
+        };
+        for (part, result) in [(1, &answers.part_one), (2, &answers.part_two)] {
+            let verdict = book.check(day, part, result);
+            match verdict {
+                Verdict::Pass => passed += 1,
+                Verdict::Fail { .. } => failed += 1,
+                Verdict::Missing { .. } => missing += 1,
+            }
+            println!("Day {day:02} part {part}: {verdict}");
+        }
+    }
+    println!("\n{passed} passed, {failed} failed, {missing} missing");
+    Ok(failed)
+}
+
+/// Scaffold a new day in this crate's sources
+fn new_day(day: u32, dir: Option<PathBuf>) -> Result<usize> {
+    let dir = match dir {
+        Some(dir) => dir,
+        None => std::env::current_dir()?,
+    };
+    let src_dir = dir.join("src");
+    for path in scaffold::new_day(&src_dir, day)? {
+        println!("created {}", path.display());
+    }
+    println!(
+        "registered day {day:02} in {}",
+        src_dir.join("days").join("mod.rs").display()
+    );
+    Ok(0)
+}
+
+fn report(result: Result<usize>) -> usize {
+    result.unwrap_or_else(|e| {
+        eprintln!("error: {e}");
+        1
+    })
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let failed = match cli.command {
+        Command::Run { days, all, input } => run(if all { None } else { days }, input),
+        Command::Bench {
+            days,
+            all,
+            bench: args,
+            input,
+        } => report(bench(if all { None } else { days }, input, args)),
+        Command::Fetch { days, all } => fetch(if all { None } else { days }),
+        Command::Record {
+            days,
+            all,
+            force,
+            answers,
+        } => report(record(if all { None } else { days }, force, answers)),
+        Command::New { day, dir } => report(new_day(day, dir)),
+        Command::Verify { days, answers } => report(verify(days, answers)),
+    };
+    match failed {
+        0 => ExitCode::SUCCESS,
+        _ => ExitCode::FAILURE,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_day_range() {
+        assert_eq!("5".parse(), Ok(DayRange(5..=5)));
+        assert_eq!("3..=11".parse(), Ok(DayRange(3..=11)));
+        assert_eq!("3..11".parse(), Ok(DayRange(3..=10)));
+        assert!("11..3".parse::<DayRange>().is_err());
+        assert!("five".parse::<DayRange>().is_err());
+    }
 }
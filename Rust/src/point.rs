@@ -1,8 +1,25 @@
 use std::{
     fmt::{Debug, Display},
-    ops::{Add, AddAssign, Sub},
+    hash::Hash,
+    ops::{Add, AddAssign, Mul, Neg, Sub},
 };
 
+/// Position on some kind of grid: a step to any of `STEPS` leads to an
+/// adjacent position, so searches and containers can work with any of them
+pub trait Coord:
+    Copy + Eq + Hash + Debug + Add<Output = Self> + Sub<Output = Self> + 'static
+{
+    /// Offsets to the adjacent positions
+    const STEPS: &'static [Self];
+
+    /// Number of steps between two positions
+    fn distance(&self, other: &Self) -> i64;
+
+    fn neighbors(self) -> impl Iterator<Item = Self> {
+        Self::STEPS.iter().map(move |step| self + *step)
+    }
+}
+
 const NEIGHBORS_4: [Point; 4] = [
     Point { x: -1, y: 0 },
     Point { x: 0, y: -1 },
@@ -98,6 +115,195 @@ impl Point {
         NEIGHBORS_8.map(|n| n + *self)
     }
 }
+
+impl Coord for Point {
+    const STEPS: &'static [Self] = &NEIGHBORS_4;
+
+    fn distance(&self, other: &Self) -> i64 {
+        self.manhattan_distance(other)
+    }
+}
+
+const NEIGHBORS_6: [Point3; 6] = [
+    Point3 { x: -1, y: 0, z: 0 },
+    Point3 { x: 1, y: 0, z: 0 },
+    Point3 { x: 0, y: -1, z: 0 },
+    Point3 { x: 0, y: 1, z: 0 },
+    Point3 { x: 0, y: 0, z: -1 },
+    Point3 { x: 0, y: 0, z: 1 },
+];
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Display for Point3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+impl From<(i64, i64, i64)> for Point3 {
+    fn from(value: (i64, i64, i64)) -> Self {
+        let (x, y, z) = value;
+        Point3::new(x, y, z)
+    }
+}
+
+impl Add for Point3 {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Point3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl AddAssign for Point3 {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl Neg for Point3 {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Point3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Mul<i64> for Point3 {
+    type Output = Self;
+    fn mul(self, rhs: i64) -> Self::Output {
+        Point3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl Point3 {
+    pub fn new(x: i64, y: i64, z: i64) -> Self {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan_distance(&self, other: &Point3) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    /// Neighbors sharing a face
+    pub fn get_6_neighbors(&self) -> [Point3; 6] {
+        NEIGHBORS_6.map(|n| n + *self)
+    }
+
+    /// Neighbors sharing a face, an edge or a corner
+    pub fn get_26_neighbors(&self) -> [Point3; 26] {
+        let mut neighbors = [*self; 26];
+        let offsets = (-1..=1)
+            .flat_map(|x| (-1..=1).flat_map(move |y| (-1..=1).map(move |z| Point3::new(x, y, z))))
+            .filter(|d| *d != Point3::default());
+        for (n, d) in neighbors.iter_mut().zip(offsets) {
+            *n += d;
+        }
+        neighbors
+    }
+}
+
+impl Coord for Point3 {
+    const STEPS: &'static [Self] = &NEIGHBORS_6;
+
+    fn distance(&self, other: &Self) -> i64 {
+        self.manhattan_distance(other)
+    }
+}
+
+const HEX_NEIGHBORS: [Hex; 6] = [
+    Hex { q: 1, r: 0 },
+    Hex { q: 1, r: -1 },
+    Hex { q: 0, r: -1 },
+    Hex { q: -1, r: 0 },
+    Hex { q: -1, r: 1 },
+    Hex { q: 0, r: 1 },
+];
+
+/// Hexagonal grid position in axial coordinates. The third cube coordinate
+/// is implied by `q + r + s == 0`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Hex {
+    pub q: i64,
+    pub r: i64,
+}
+
+impl Display for Hex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.q, self.r, self.s())
+    }
+}
+
+impl From<(i64, i64)> for Hex {
+    fn from(value: (i64, i64)) -> Self {
+        let (q, r) = value;
+        Hex::new(q, r)
+    }
+}
+
+impl Add for Hex {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Hex::new(self.q + rhs.q, self.r + rhs.r)
+    }
+}
+
+impl AddAssign for Hex {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Hex {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Hex::new(self.q - rhs.q, self.r - rhs.r)
+    }
+}
+
+impl Hex {
+    pub fn new(q: i64, r: i64) -> Self {
+        Hex { q, r }
+    }
+
+    /// From cube coordinates, which must sum to zero
+    pub fn from_cube(q: i64, r: i64, s: i64) -> Self {
+        assert_eq!(q + r + s, 0, "cube coordinates must sum to 0");
+        Hex { q, r }
+    }
+
+    pub fn s(&self) -> i64 {
+        -self.q - self.r
+    }
+
+    pub fn hex_distance(&self, other: &Hex) -> i64 {
+        let d = *self - *other;
+        (d.q.abs() + d.r.abs() + d.s().abs()) / 2
+    }
+
+    pub fn get_6_neighbors(&self) -> [Hex; 6] {
+        HEX_NEIGHBORS.map(|n| n + *self)
+    }
+}
+
+impl Coord for Hex {
+    const STEPS: &'static [Self] = &HEX_NEIGHBORS;
+
+    fn distance(&self, other: &Self) -> i64 {
+        self.hex_distance(other)
+    }
+}
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Direction {
     Up,
@@ -136,3 +342,59 @@ impl Direction {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::search::bfs;
+
+    #[test]
+    fn test_point3() {
+        let a = Point3::from((1, 2, 3));
+        let b = Point3::new(-1, 0, 5);
+        assert_eq!(a + b, Point3::new(0, 2, 8));
+        assert_eq!(a - b, Point3::new(2, 2, -2));
+        assert_eq!(-a * 2, Point3::new(-2, -4, -6));
+        assert_eq!(a.manhattan_distance(&b), 6);
+        assert!(a.get_6_neighbors().iter().all(|n| n.distance(&a) == 1));
+        let around = a.get_26_neighbors();
+        assert!(!around.contains(&a));
+        assert_eq!(
+            around
+                .iter()
+                .collect::<std::collections::HashSet<_>>()
+                .len(),
+            26
+        );
+        assert!(around.contains(&Point3::new(2, 3, 4)));
+        assert_eq!(a.to_string(), "(1, 2, 3)");
+    }
+
+    #[test]
+    fn test_hex() {
+        let origin = Hex::default();
+        assert_eq!(Hex::from_cube(2, -1, -1), Hex::new(2, -1));
+        assert_eq!(Hex::new(2, -1).s(), -1);
+        assert_eq!(origin.hex_distance(&Hex::new(3, -1)), 3);
+        assert_eq!(Hex::new(-2, 3).hex_distance(&Hex::new(1, 1)), 3);
+        assert!(origin
+            .get_6_neighbors()
+            .iter()
+            .all(|n| n.distance(&origin) == 1));
+    }
+
+    /// Steps to `goal` found by searching neighbors, for any kind of coordinate
+    fn steps_to<C: Coord>(start: C, goal: C) -> Option<usize> {
+        bfs(start, |c| c.neighbors(), |c| *c == goal).map(|found| found.cost)
+    }
+
+    #[test]
+    fn test_coord_search_matches_distance() {
+        let (start, goal) = (Point::new(0, 0), Point::new(3, -2));
+        assert_eq!(steps_to(start, goal), Some(start.distance(&goal) as usize));
+        let (start, goal) = (Point3::new(0, 0, 0), Point3::new(1, -2, 1));
+        assert_eq!(steps_to(start, goal), Some(start.distance(&goal) as usize));
+        let (start, goal) = (Hex::new(0, 0), Hex::new(2, 1));
+        assert_eq!(steps_to(start, goal), Some(start.distance(&goal) as usize));
+    }
+}
@@ -0,0 +1,214 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::{grid::Grid2D, point::Point};
+
+/// Which cells count as touching
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Only N, S, E, W
+    Four,
+    /// Diagonals as well
+    Eight,
+}
+
+/// Set of grid cells that belong together
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Region {
+    pub points: HashSet<Point>,
+}
+
+impl Region {
+    pub fn contains(&self, p: Point) -> bool {
+        self.points.contains(&p)
+    }
+
+    /// Number of cells
+    pub fn area(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Number of cell edges between the region and anything outside it,
+    /// holes included
+    pub fn perimeter(&self) -> usize {
+        self.points
+            .iter()
+            .flat_map(|p| p.get_4_neighbors())
+            .filter(|n| !self.contains(*n))
+            .count()
+    }
+
+    /// Number of straight sides of the outline, holes included. Each side
+    /// ends in a corner, so this counts corners instead.
+    pub fn sides(&self) -> usize {
+        // Each of a cell's four corners, as the two orthogonal steps towards it
+        const CORNERS: [(Point, Point); 4] = [
+            (Point { x: 0, y: -1 }, Point { x: 1, y: 0 }),
+            (Point { x: 1, y: 0 }, Point { x: 0, y: 1 }),
+            (Point { x: 0, y: 1 }, Point { x: -1, y: 0 }),
+            (Point { x: -1, y: 0 }, Point { x: 0, y: -1 }),
+        ];
+        self.points
+            .iter()
+            .map(|&p| {
+                CORNERS
+                    .iter()
+                    .filter(|&&(a, b)| {
+                        let (side_a, side_b) = (self.contains(p + a), self.contains(p + b));
+                        // Outer corner, or inner corner of an L-bend
+                        (!side_a && !side_b) || (side_a && side_b && !self.contains(p + a + b))
+                    })
+                    .count()
+            })
+            .sum()
+    }
+}
+
+/// Every region of a grid, and which one each cell is in
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Components {
+    /// Index into `regions` for every cell
+    pub labels: Grid2D<usize>,
+    pub regions: Vec<Region>,
+}
+
+impl<T> Grid2D<T>
+where
+    T: PartialEq + Clone + Copy,
+{
+    /// In-bounds neighbors of a point with the given connectivity
+    pub fn get_neighbor_coords(&self, p: Point, connectivity: Connectivity) -> Vec<Point> {
+        match connectivity {
+            Connectivity::Four => self.get_neighbor4_coords(p),
+            Connectivity::Eight => self.get_neighbor8_coords(p),
+        }
+    }
+
+    /// Every cell reachable from `start` through cells matching `predicate`.
+    /// Empty if `start` itself is out of bounds or doesn't match.
+    pub fn flood_fill(
+        &self,
+        start: Point,
+        connectivity: Connectivity,
+        predicate: impl Fn(&T) -> bool,
+    ) -> Region {
+        let mut region = Region::default();
+        if !self.get_ref(start).is_some_and(&predicate) {
+            return region;
+        }
+        region.points.insert(start);
+        let mut queue = VecDeque::from([start]);
+        while let Some(p) = queue.pop_front() {
+            for n in self.get_neighbor_coords(p, connectivity) {
+                if predicate(self.get_ref(n).unwrap()) && region.points.insert(n) {
+                    queue.push_back(n);
+                }
+            }
+        }
+        region
+    }
+
+    /// Split the grid into regions of touching cells for which `same_region`
+    /// holds between neighbors
+    pub fn connected_components(
+        &self,
+        connectivity: Connectivity,
+        same_region: impl Fn(&T, &T) -> bool,
+    ) -> Components {
+        const UNLABELED: usize = usize::MAX;
+        let mut labels = Grid2D {
+            width: self.width,
+            height: self.height,
+            cells: vec![UNLABELED; self.cells.len()],
+        };
+        let mut regions = vec![];
+        for idx in 0..self.cells.len() {
+            if labels.cells[idx] != UNLABELED {
+                continue;
+            }
+            let label = regions.len();
+            let start = Point::from_index(idx, self.width);
+            let mut region = Region::default();
+            region.points.insert(start);
+            labels.set(start, label);
+            let mut queue = VecDeque::from([start]);
+            while let Some(p) = queue.pop_front() {
+                let cell = self.get_ref(p).unwrap();
+                for n in self.get_neighbor_coords(p, connectivity) {
+                    if labels.get_ref(n) == Some(&UNLABELED)
+                        && same_region(cell, self.get_ref(n).unwrap())
+                    {
+                        labels.set(n, label);
+                        region.points.insert(n);
+                        queue.push_back(n);
+                    }
+                }
+            }
+            regions.push(region);
+        }
+        Components { labels, regions }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const GARDEN: &str = "\
+AAAA
+BBCD
+BBCC
+EEEC";
+
+    #[test]
+    fn test_flood_fill() {
+        let grid: Grid2D<char> = "..#..\n.#...\n#..#.".into();
+        let open = |c: &char| *c == '.';
+        let corner = grid.flood_fill(Point::new(0, 0), Connectivity::Four, open);
+        assert_eq!(corner.area(), 3);
+        let rest = grid.flood_fill(Point::new(4, 0), Connectivity::Four, open);
+        assert_eq!(rest.area(), 8);
+        // Diagonal gaps let the fill through
+        let all = grid.flood_fill(Point::new(0, 0), Connectivity::Eight, open);
+        assert_eq!(all.area(), 11);
+        assert!(grid
+            .flood_fill(Point::new(2, 0), Connectivity::Four, open)
+            .points
+            .is_empty());
+    }
+
+    #[test]
+    fn test_region_stats() {
+        let grid: Grid2D<char> = GARDEN.into();
+        let components = grid.connected_components(Connectivity::Four, |a, b| a == b);
+        let stats: Vec<(usize, usize, usize)> = components
+            .regions
+            .iter()
+            .map(|r| (r.area(), r.perimeter(), r.sides()))
+            .collect();
+        // A, B, C, D, E in order of their first cell
+        assert_eq!(
+            stats,
+            vec![(4, 10, 4), (4, 8, 4), (4, 10, 8), (1, 4, 4), (3, 8, 4)]
+        );
+        assert_eq!(components.labels.get_ref(Point::new(3, 3)), Some(&2));
+        assert_eq!(components.labels.get_ref(Point::new(0, 3)), Some(&4));
+    }
+
+    #[test]
+    fn test_region_with_hole() {
+        let grid: Grid2D<char> = "OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO".into();
+        let components = grid.connected_components(Connectivity::Four, |a, b| a == b);
+        assert_eq!(components.regions.len(), 5);
+        let outer = &components.regions[0];
+        assert_eq!(outer.area(), 21);
+        assert_eq!(outer.perimeter(), 36);
+        assert_eq!(outer.sides(), 20);
+        // The X cells are two apart, so they stay separate even with diagonals
+        let diagonal = grid.connected_components(Connectivity::Eight, |a, b| a == b);
+        assert_eq!(diagonal.regions.len(), 5);
+        let checkers: Grid2D<char> = "X.\n.X".into();
+        let diagonal = checkers.connected_components(Connectivity::Eight, |a, b| a == b);
+        assert_eq!(diagonal.regions.len(), 2);
+        assert_eq!(diagonal.regions[0].area(), 2);
+    }
+}
@@ -0,0 +1,275 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{AocError, Result};
+
+/// Starting point for `src/days/dayNN.rs`. `{{NN}}` is the zero-padded day and
+/// `{{DAY}}` the plain number.
+const DAY_TEMPLATE: &str = r#"#[allow(unused_imports)]
+use crate::{get_puzzle_input_lines, get_puzzle_input_string, point::Point};
+use crate::{grid::Grid2D, solution::Solution, AocError, Result};
+
+pub struct Day{{NN}};
+
+fn parse_input(input: &str) -> Grid2D<char> {
+    input.into()
+}
+
+fn part_1(_grid: &Grid2D<char>) -> Option<usize> {
+    None
+}
+
+fn part_2(_grid: &Grid2D<char>) -> Option<usize> {
+    None
+}
+
+impl Solution for Day{{NN}} {
+    const DAY: u32 = {{DAY}};
+    const EXAMPLES: &'static [&'static str] = &[SAMPLE];
+    type Input<'a> = Grid2D<char>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Ok(parse_input(input))
+    }
+
+    fn part_one(grid: &Self::Input<'_>) -> Result<Self::PartOne> {
+        part_1(grid).ok_or(AocError::Unsolved {
+            day: Self::DAY,
+            part: 1,
+        })
+    }
+
+    fn part_two(grid: &Self::Input<'_>) -> Result<Self::PartTwo> {
+        part_2(grid).ok_or(AocError::Unsolved {
+            day: Self::DAY,
+            part: 2,
+        })
+    }
+}
+
+// Replace with the example from the puzzle text
+const SAMPLE: &str = "\
+.#.
+#.#
+.#.";
+
+crate::aoc_examples! {
+    solution: Day{{NN}},
+    input: SAMPLE,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let grid = parse_input(SAMPLE);
+        assert_eq!(grid.width, 3);
+        assert_eq!(grid.get_ref(Point::new(1, 0)), Some(&'#'));
+    }
+}
+"#;
+
+/// Starting point for `src/bin/dayNN.rs`
+const BIN_TEMPLATE: &str = r#"use std::process::ExitCode;
+
+use rust_aoc2023::{days::day{{NN}}::Day{{NN}}, solution::run};
+
+fn main() -> ExitCode {
+    run::<Day{{NN}}>()
+}
+"#;
+
+fn render(template: &str, day: u32) -> String {
+    template
+        .replace("{{NN}}", &format!("{:02}", day))
+        .replace("{{DAY}}", &day.to_string())
+}
+
+/// Insert `line` among the consecutive lines of `text` starting with `prefix`,
+/// keeping them sorted
+fn insert_sorted(text: &str, prefix: &str, line: &str) -> String {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let matching: Vec<usize> = (0..lines.len())
+        .filter(|&i| lines[i].starts_with(prefix))
+        .collect();
+    let at = matching
+        .iter()
+        .find(|&&i| lines[i] > line)
+        .copied()
+        .unwrap_or_else(|| matching.last().map_or(0, |i| i + 1));
+    lines.insert(at, line);
+    lines.join("\n") + "\n"
+}
+
+/// Write the solution module and binary for `day` into the crate's `src_dir`
+/// and register the module in `days/mod.rs`. Nothing is written if either file
+/// exists already. Returns the files that were created.
+pub fn new_day(src_dir: &Path, day: u32) -> Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        return Err(AocError::InvalidDay { day });
+    }
+    let registry = src_dir.join("days").join("mod.rs");
+    if !registry.is_file() {
+        return Err(AocError::NotACrate {
+            path: src_dir.parent().unwrap_or(src_dir).to_path_buf(),
+        });
+    }
+    let module = src_dir.join("days").join(format!("day{:02}.rs", day));
+    let bin = src_dir.join("bin").join(format!("day{:02}.rs", day));
+    if let Some(path) = [&module, &bin].into_iter().find(|p| p.exists()) {
+        return Err(AocError::AlreadyExists { path: path.clone() });
+    }
+
+    let text = fs::read_to_string(&registry)?;
+    let text = insert_sorted(&text, "pub mod day", &format!("pub mod day{:02};", day));
+    let text = insert_sorted(
+        &text,
+        "    Registered::new::<day",
+        &render("    Registered::new::<day{{NN}}::Day{{NN}}>(),", day),
+    );
+
+    fs::write(&module, render(DAY_TEMPLATE, day))?;
+    fs::write(&bin, render(BIN_TEMPLATE, day))?;
+    fs::write(&registry, text)?;
+    Ok(vec![module, bin])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const REGISTRY: &str = "\
+pub mod day01;
+pub mod day20;
+
+use crate::solution::Registered;
+
+pub const SOLUTIONS: &[Registered] = &[
+    Registered::new::<day01::Day01>(),
+    Registered::new::<day20::Day20>(),
+];
+";
+
+    fn temp_src_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "rust_aoc2023_scaffold_{}_{}",
+            std::process::id(),
+            name
+        ));
+        _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("days")).unwrap();
+        fs::create_dir_all(dir.join("bin")).unwrap();
+        fs::write(dir.join("days").join("mod.rs"), REGISTRY).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_render() {
+        let module = render(DAY_TEMPLATE, 7);
+        let bin = render(BIN_TEMPLATE, 7);
+        for text in [&module, &bin] {
+            assert!(!text.contains("{{"), "unrendered placeholder in\n{text}");
+        }
+        assert!(module.starts_with(
+            "#[allow(unused_imports)]\n\
+             use crate::{get_puzzle_input_lines, get_puzzle_input_string, point::Point};\n\
+             use crate::{grid::Grid2D, solution::Solution, AocError, Result};\n"
+        ));
+        for line in [
+            "pub struct Day07;",
+            "impl Solution for Day07 {",
+            "    const DAY: u32 = 7;",
+            "    solution: Day07,",
+        ] {
+            assert!(module.lines().any(|l| l == line), "missing {line:?}");
+        }
+        // `run` loads the default input for the day and prints both answers
+        assert_eq!(
+            bin,
+            "\
+use std::process::ExitCode;
+
+use rust_aoc2023::{days::day07::Day07, solution::run};
+
+fn main() -> ExitCode {
+    run::<Day07>()
+}
+"
+        );
+    }
+
+    #[test]
+    fn test_new_day() {
+        let src_dir = temp_src_dir("new");
+        let created = new_day(&src_dir, 18).unwrap();
+        assert_eq!(
+            created,
+            vec![
+                src_dir.join("days").join("day18.rs"),
+                src_dir.join("bin").join("day18.rs")
+            ]
+        );
+        assert_eq!(
+            fs::read_to_string(&created[0]).unwrap(),
+            render(DAY_TEMPLATE, 18)
+        );
+        assert_eq!(
+            fs::read_to_string(&created[1]).unwrap(),
+            render(BIN_TEMPLATE, 18)
+        );
+        let registry = fs::read_to_string(src_dir.join("days").join("mod.rs")).unwrap();
+        assert_eq!(
+            registry,
+            "\
+pub mod day01;
+pub mod day18;
+pub mod day20;
+
+use crate::solution::Registered;
+
+pub const SOLUTIONS: &[Registered] = &[
+    Registered::new::<day01::Day01>(),
+    Registered::new::<day18::Day18>(),
+    Registered::new::<day20::Day20>(),
+];
+"
+        );
+        fs::remove_dir_all(src_dir).unwrap();
+    }
+
+    #[test]
+    fn test_new_day_refuses_overwrite() {
+        let src_dir = temp_src_dir("overwrite");
+        let bin = src_dir.join("bin").join("day18.rs");
+        fs::write(&bin, "fn main() {}\n").unwrap();
+        match new_day(&src_dir, 18) {
+            Err(AocError::AlreadyExists { path }) => assert_eq!(path, bin),
+            other => panic!("expected AlreadyExists, got {other:?}"),
+        }
+        assert!(!src_dir.join("days").join("day18.rs").exists());
+        assert_eq!(fs::read_to_string(&bin).unwrap(), "fn main() {}\n");
+        let registry = fs::read_to_string(src_dir.join("days").join("mod.rs")).unwrap();
+        assert_eq!(registry, REGISTRY);
+        fs::remove_dir_all(src_dir).unwrap();
+    }
+
+    #[test]
+    fn test_new_day_outside_a_crate() {
+        let dir = std::env::temp_dir().join(format!(
+            "rust_aoc2023_scaffold_{}_not_a_crate",
+            std::process::id()
+        ));
+        let src_dir = dir.join("src");
+        assert!(matches!(
+            new_day(&src_dir, 18),
+            Err(AocError::NotACrate { path }) if path == dir
+        ));
+        assert!(!src_dir.exists());
+    }
+}
@@ -0,0 +1,254 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Cheapest (or shortest) way to a goal, as found by one of the searches
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Found<S, C> {
+    /// Total cost from the start to the goal
+    pub cost: C,
+    /// Every state from the start to the goal, both included
+    pub path: Vec<S>,
+}
+
+/// Visited state, pointing back to the state it was reached from
+struct Node<S, C> {
+    state: S,
+    parent: Option<usize>,
+    cost: C,
+}
+
+/// Follow parent links from `nodes[index]` back to the start
+fn reconstruct<S: Clone, C: Copy>(nodes: &[Node<S, C>], index: usize) -> Found<S, C> {
+    let cost = nodes[index].cost;
+    let mut path = vec![];
+    let mut next = Some(index);
+    while let Some(i) = next {
+        path.push(nodes[i].state.clone());
+        next = nodes[i].parent;
+    }
+    path.reverse();
+    Found { cost, path }
+}
+
+/// Breadth-first search where every step costs 1. `successors` yields the
+/// states reachable in one step; the cost of the result is the number of steps.
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut seen = HashSet::from([start.clone()]);
+    let mut nodes = vec![Node {
+        state: start,
+        parent: None,
+        cost: 0,
+    }];
+    let mut queue = VecDeque::from([0]);
+    while let Some(index) = queue.pop_front() {
+        if is_goal(&nodes[index].state) {
+            return Some(reconstruct(&nodes, index));
+        }
+        let cost = nodes[index].cost + 1;
+        for next in successors(&nodes[index].state) {
+            if seen.insert(next.clone()) {
+                queue.push_back(nodes.len());
+                nodes.push(Node {
+                    state: next,
+                    parent: Some(index),
+                    cost,
+                });
+            }
+        }
+    }
+    None
+}
+
+/// Dijkstra's algorithm: `successors` yields `(state, cost of the step)`.
+/// Step costs must not be negative.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    a_star(start, successors, is_goal, |_| C::default())
+}
+
+/// Heap entry, ordered so `BinaryHeap` pops the lowest estimate first
+struct Candidate<C> {
+    estimate: C,
+    cost: C,
+    index: usize,
+}
+
+impl<C: Ord> Ord for Candidate<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .cmp(&self.estimate)
+            // Prefer the candidate that got further on equal estimates
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+impl<C: Ord> PartialOrd for Candidate<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Ord> PartialEq for Candidate<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<C: Ord> Eq for Candidate<C> {}
+
+/// A* search: like `dijkstra`, guided by `heuristic`, an estimate of the
+/// remaining cost to a goal. The result is only the cheapest path if the
+/// heuristic never overestimates.
+pub fn a_star<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+    mut heuristic: impl FnMut(&S) -> C,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut best = HashMap::from([(start.clone(), C::default())]);
+    let mut heap = BinaryHeap::from([Candidate {
+        estimate: heuristic(&start),
+        cost: C::default(),
+        index: 0,
+    }]);
+    let mut nodes = vec![Node {
+        state: start,
+        parent: None,
+        cost: C::default(),
+    }];
+    while let Some(Candidate { cost, index, .. }) = heap.pop() {
+        let state = &nodes[index].state;
+        // Skip entries superseded by a cheaper way to the same state
+        if best.get(state).is_some_and(|&b| cost > b) {
+            continue;
+        }
+        if is_goal(state) {
+            return Some(reconstruct(&nodes, index));
+        }
+        for (next, step) in successors(state) {
+            let next_cost = cost + step;
+            if best.get(&next).is_some_and(|&b| b <= next_cost) {
+                continue;
+            }
+            best.insert(next.clone(), next_cost);
+            heap.push(Candidate {
+                estimate: next_cost + heuristic(&next),
+                cost: next_cost,
+                index: nodes.len(),
+            });
+            nodes.push(Node {
+                state: next,
+                parent: Some(index),
+                cost: next_cost,
+            });
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{grid::Grid2D, point::Point};
+
+    const MAZE: &str = "\
+S.#.....
+.##.###.
+....#.G.
+.##...#.";
+
+    fn open_neighbors(grid: &Grid2D<char>, p: Point) -> Vec<Point> {
+        grid.get_neighbor4_coords(p)
+            .into_iter()
+            .filter(|n| grid.get_ref(*n) != Some(&'#'))
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid: Grid2D<char> = MAZE.into();
+        let start = grid.find_item_coord(&'S').unwrap();
+        let goal = grid.find_item_coord(&'G').unwrap();
+        let found = bfs(start, |p| open_neighbors(&grid, *p), |p| *p == goal).unwrap();
+        assert_eq!(found.cost, 10);
+        assert_eq!(found.path.len(), 11);
+        assert_eq!(found.path[0], start);
+        assert_eq!(found.path[10], goal);
+        assert!(found
+            .path
+            .windows(2)
+            .all(|w| w[0].manhattan_distance(&w[1]) == 1));
+
+        let corner = Point::new(7, 3);
+        assert_eq!(
+            bfs(goal, |p| open_neighbors(&grid, *p), |p| *p == corner).map(|f| f.cost),
+            Some(2)
+        );
+        assert_eq!(bfs(start, |_| vec![], |p| *p == goal), None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // a -1-> b -1-> c -1-> d, and a shortcut a -5-> d that costs more
+        let edges = HashMap::from([
+            ('a', vec![('b', 1), ('d', 5)]),
+            ('b', vec![('c', 1)]),
+            ('c', vec![('d', 1)]),
+        ]);
+        let successors = |s: &char| edges.get(s).cloned().unwrap_or_default();
+        let found = dijkstra('a', successors, |s| *s == 'd').unwrap();
+        assert_eq!(found.cost, 3);
+        assert_eq!(found.path, vec!['a', 'b', 'c', 'd']);
+        assert_eq!(dijkstra('d', successors, |s| *s == 'a'), None);
+    }
+
+    #[test]
+    fn test_a_star_matches_dijkstra() {
+        let grid: Grid2D<char> = MAZE.into();
+        let start = grid.find_item_coord(&'S').unwrap();
+        let goal = grid.find_item_coord(&'G').unwrap();
+        // Moving down costs 3, every other step 1
+        let successors = |p: &Point| {
+            open_neighbors(&grid, *p)
+                .into_iter()
+                .map(|n| (n, if n.y > p.y { 3 } else { 1 }))
+                .collect::<Vec<_>>()
+        };
+        let expected = dijkstra(start, successors, |p| *p == goal).unwrap();
+        let found = a_star(
+            start,
+            successors,
+            |p| *p == goal,
+            |p| p.manhattan_distance(&goal),
+        )
+        .unwrap();
+        assert_eq!(found.cost, expected.cost);
+        assert_eq!(found.cost, 16);
+    }
+}
@@ -0,0 +1,109 @@
+use std::{fmt::Display, process::ExitCode};
+
+use crate::{
+    bench::{measure, Timings},
+    input::InputSource,
+    Result,
+};
+
+/// Common interface for a day's puzzle. The input is parsed once and the
+/// parsed value is shared by both parts.
+pub trait Solution {
+    /// Day of the advent calendar this solves
+    const DAY: u32;
+    /// Example inputs from the puzzle text, selectable with `InputSource::Example`
+    const EXAMPLES: &'static [&'static str] = &[];
+
+    /// Parsed puzzle input, allowed to borrow from the raw input string
+    type Input<'a>;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>>;
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::PartOne>;
+    fn part_two(input: &Self::Input<'_>) -> Result<Self::PartTwo>;
+}
+
+/// Displayable answers for both parts of a day. A part that fails (or is not
+/// solved yet) doesn't prevent the other part from reporting its answer.
+#[derive(Debug)]
+pub struct Answers {
+    pub part_one: Result<String>,
+    pub part_two: Result<String>,
+    /// How long parsing and each part took
+    pub timings: Timings,
+}
+
+/// Parse `input` and run both parts of solution `S`, timing each phase
+pub fn solve<S: Solution>(input: &str) -> Result<Answers> {
+    let (parsed, parse) = measure(|| S::parse(input));
+    let parsed = parsed?;
+    let (part_one, part_one_timing) =
+        measure(|| S::part_one(&parsed).map(|answer| answer.to_string()));
+    let (part_two, part_two_timing) =
+        measure(|| S::part_two(&parsed).map(|answer| answer.to_string()));
+    Ok(Answers {
+        part_one,
+        part_two,
+        timings: Timings {
+            parse,
+            part_one: part_one_timing,
+            part_two: part_two_timing,
+        },
+    })
+}
+
+/// Type-erased entry in the solution registry (see `days::SOLUTIONS`)
+#[derive(Debug, Clone, Copy)]
+pub struct Registered {
+    pub day: u32,
+    pub examples: &'static [&'static str],
+    pub solve: fn(&str) -> Result<Answers>,
+}
+
+impl Registered {
+    pub const fn new<S: Solution>() -> Self {
+        Registered {
+            day: S::DAY,
+            examples: S::EXAMPLES,
+            solve: solve::<S>,
+        }
+    }
+
+    /// Load this day's input from `source`
+    pub fn load_input(&self, source: &InputSource) -> Result<String> {
+        source.load(self.day, self.examples)
+    }
+
+    /// Load this day's input from `source` and solve it
+    pub fn answers(&self, source: &InputSource) -> Result<Answers> {
+        (self.solve)(&self.load_input(source)?)
+    }
+
+    /// Load this day's input from `source`, solve it and print the answers
+    pub fn run(&self, source: &InputSource) -> Result<()> {
+        let answers = self.answers(source)?;
+        println!("Day {:02}", self.day);
+        print_part(1, &answers.part_one);
+        print_part(2, &answers.part_two);
+        Ok(())
+    }
+}
+
+fn print_part(part: u32, answer: &Result<String>) {
+    match answer {
+        Ok(answer) => println!("Part {part}: {answer}"),
+        Err(e) => println!("Part {part}: error: {e}"),
+    }
+}
+
+/// Entry point for the per-day binaries in `src/bin`
+pub fn run<S: Solution>() -> ExitCode {
+    match Registered::new::<S>().run(&InputSource::Default) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use std::{collections::HashMap, fmt::Display};
+
+use crate::{grid::Grid2D, point::Point};
+
+/// Unbounded 2D grid that only stores cells which were set. Every other cell
+/// reads as the default value, and coordinates may be negative.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+    default: T,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new(default: T) -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            default,
+        }
+    }
+
+    /// Value of a cell, the default if it was never set
+    pub fn get(&self, p: Point) -> &T {
+        self.cells.get(&p).unwrap_or(&self.default)
+    }
+
+    /// Value of a cell only if it was set
+    pub fn get_ref(&self, p: Point) -> Option<&T> {
+        self.cells.get(&p)
+    }
+
+    pub fn get_mut_ref(&mut self, p: Point) -> Option<&mut T> {
+        self.cells.get_mut(&p)
+    }
+
+    /// Set a cell, returning its previous value if it had one
+    pub fn set(&mut self, p: Point, item: T) -> Option<T> {
+        self.cells.insert(p, item)
+    }
+
+    /// Forget a cell so it reads as the default again
+    pub fn remove(&mut self, p: Point) -> Option<T> {
+        self.cells.remove(&p)
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        self.cells.contains_key(&p)
+    }
+
+    pub fn default_value(&self) -> &T {
+        &self.default
+    }
+
+    /// Number of cells that were set
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Cells that were set, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(p, item)| (*p, item))
+    }
+
+    /// Smallest and largest corner (both included) of the rectangle around
+    /// every set cell, or None if there are none
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        let mut points = self.cells.keys();
+        let first = *points.next()?;
+        Some(points.fold((first, first), |(min, max), p| {
+            (
+                Point::new(min.x.min(p.x), min.y.min(p.y)),
+                Point::new(max.x.max(p.x), max.y.max(p.y)),
+            )
+        }))
+    }
+
+    /// N, S, E, W neighbors of a point with their values
+    pub fn get_neighbor4_cells(&self, p: Point) -> [(Point, &T); 4] {
+        p.get_4_neighbors().map(|n| (n, self.get(n)))
+    }
+
+    /// All 8 neighbors of a point with their values
+    pub fn get_neighbor8_cells(&self, p: Point) -> [(Point, &T); 8] {
+        p.get_8_neighbors().map(|n| (n, self.get(n)))
+    }
+}
+
+impl<T> SparseGrid<T>
+where
+    T: PartialEq + Clone + Copy,
+{
+    /// Sparse copy of `grid` keeping only the cells that aren't `default`
+    pub fn from_grid(grid: &Grid2D<T>, default: T) -> Self {
+        let cells = grid
+            .cells
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| **item != default)
+            .map(|(idx, item)| (Point::from_index(idx, grid.width), *item))
+            .collect();
+        SparseGrid { cells, default }
+    }
+
+    /// Dense copy of the cells within `bounds`, together with the point that
+    /// ends up at (0, 0). An empty grid becomes a 0x0 grid.
+    pub fn to_grid(&self) -> (Point, Grid2D<T>) {
+        let Some((min, max)) = self.bounds() else {
+            let empty = Grid2D {
+                width: 0,
+                height: 0,
+                cells: vec![],
+            };
+            return (Point::default(), empty);
+        };
+        let cells = (min.y..=max.y)
+            .flat_map(|y| (min.x..=max.x).map(move |x| Point::new(x, y)))
+            .map(|p| *self.get(p))
+            .collect();
+        let grid = Grid2D {
+            width: max.x - min.x + 1,
+            height: max.y - min.y + 1,
+            cells,
+        };
+        (min, grid)
+    }
+}
+
+impl<T> Display for SparseGrid<T>
+where
+    T: Display,
+{
+    /// Render the rectangle around every set cell
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some((min, max)) = self.bounds() else {
+            return Ok(());
+        };
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                write!(f, "{}", self.get(Point::new(x, y)))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_get_and_bounds() {
+        let mut grid = SparseGrid::new('.');
+        assert_eq!(grid.bounds(), None);
+        assert_eq!(grid.to_string(), "");
+        grid.set(Point::new(-2, 1), '#');
+        grid.set(Point::new(1, -1), '#');
+        assert_eq!(grid.get(Point::new(-2, 1)), &'#');
+        assert_eq!(grid.get(Point::new(100, 100)), &'.');
+        assert_eq!(grid.get_ref(Point::new(100, 100)), None);
+        assert_eq!(grid.bounds(), Some((Point::new(-2, -1), Point::new(1, 1))));
+        assert_eq!(grid.to_string(), "...#\n....\n#...\n");
+        assert_eq!(grid.remove(Point::new(1, -1)), Some('#'));
+        assert_eq!(grid.bounds(), Some((Point::new(-2, 1), Point::new(-2, 1))));
+        assert_eq!(grid.len(), 1);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let mut grid = SparseGrid::new(0);
+        grid.set(Point::new(0, -1), 5);
+        grid.set(Point::new(-1, -1), 7);
+        let sum4: i32 = grid
+            .get_neighbor4_cells(Point::new(0, 0))
+            .iter()
+            .map(|(_, v)| **v)
+            .sum();
+        let sum8: i32 = grid
+            .get_neighbor8_cells(Point::new(0, 0))
+            .iter()
+            .map(|(_, v)| **v)
+            .sum();
+        assert_eq!((sum4, sum8), (5, 12));
+    }
+
+    #[test]
+    fn test_grid_round_trip() {
+        let dense: Grid2D<char> = "..#.\n....\n.#..".into();
+        let sparse = SparseGrid::from_grid(&dense, '.');
+        assert_eq!(sparse.len(), 2);
+        assert!(sparse.contains(Point::new(2, 0)));
+        // Only the rectangle around the set cells is kept
+        let (origin, trimmed) = sparse.to_grid();
+        assert_eq!(origin, Point::new(1, 0));
+        assert_eq!(trimmed.to_string(), ".#\n..\n#.\n");
+        assert_eq!(sparse.to_string(), trimmed.to_string());
+    }
+}